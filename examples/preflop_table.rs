@@ -0,0 +1,39 @@
+//! Generates the preflop equity table embedded in the crate
+//!
+//! cargo run --release --example preflop_table > src/core/preflop/table.rs
+
+use poker::core::preflop::{simulate_equity, HandClass, HAND_CLASSES, MAX_OPPONENTS};
+use rayon::prelude::*;
+
+const ROUNDS: u32 = 20000;
+
+fn main() {
+    let rows = (0..HAND_CLASSES)
+        .into_par_iter()
+        .map(|i| {
+            let class = HandClass::from_index(i);
+            let equities = (1..=MAX_OPPONENTS)
+                .map(|n| {
+                    let seed = (i * MAX_OPPONENTS + n) as u64;
+                    simulate_equity(class, n, ROUNDS, seed).expect("Cannot simulate the hand")
+                })
+                .map(|e| format!("{e:.4}"))
+                .collect::<Vec<_>>();
+
+            format!("    [{}], //{class}", equities.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    println!(
+        "//Generated by examples/preflop_table.rs with {ROUNDS} rounds for every hand, don't edit"
+    );
+    println!("#![allow(clippy::approx_constant)]");
+    println!();
+    println!("///Equity of every hand class against 1 to 9 random opponents");
+    println!("#[rustfmt::skip]");
+    println!("pub const PREFLOP_EQUITY: [[f32; 9]; 169] = [");
+    for row in rows {
+        println!("{row}");
+    }
+    println!("];");
+}
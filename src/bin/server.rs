@@ -0,0 +1,31 @@
+use std::env;
+
+use poker::net::server::{TableConfig, TableServer};
+
+///Usage: server [address] [seats] [remote seats]
+fn main() {
+    env_logger::init();
+
+    let args: Vec<String> = env::args().collect();
+    let addr = args.get(1).map(String::as_str).unwrap_or("0.0.0.0:7878");
+    let mut config = TableConfig::default();
+    if let Some(seats) = args.get(2) {
+        config.seats = seats.parse().expect("The number of seats must be a number");
+    }
+    if let Some(remote) = args.get(3) {
+        config.remote_seats = remote
+            .parse()
+            .expect("The number of remote seats must be a number");
+    }
+
+    let server = TableServer::bind(addr, config).expect("Couldn't open the table");
+    println!(
+        "Waiting for players in {}",
+        server.local_addr().expect("Couldn't get the address")
+    );
+
+    match server.run() {
+        Ok(stacks) => println!("The table closed with stacks {stacks:?}"),
+        Err(e) => eprintln!("The table closed with an error: {e}"),
+    }
+}
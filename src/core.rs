@@ -1,10 +1,16 @@
 pub mod action;
 pub mod card;
+pub mod clock;
 ///The code necessary to set up a poker engine and play, without UI
 pub mod deck;
+pub mod draw;
 pub mod engine;
+pub mod env;
 pub mod error;
 pub mod hand;
 pub mod player;
+pub mod preflop;
 pub mod rank;
+pub mod snapshot;
 pub mod state;
+pub mod stats;
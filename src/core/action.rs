@@ -1,22 +1,50 @@
 use std::fmt::Debug;
 
 use game_action::GameAction;
+use serde::{Deserialize, Serialize};
 
 use super::state::GameState;
 
 pub mod game_action;
 pub mod mpsc_queue;
+pub mod seat_queue;
+pub mod stats_queue;
 pub mod test_queue;
 
-#[derive(Debug, Clone)]
+///The stream of events a message belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SeatView {
+    ///Seen by every seat and the spectators
+    Public,
+    ///Only seen by the player in the seat
+    Private(usize),
+}
+
+impl SeatView {
+    ///A seat sees the public stream and its own private stream, a spectator only the public one
+    pub fn is_visible_to(&self, seat: Option<usize>) -> bool {
+        match self {
+            SeatView::Public => true,
+            SeatView::Private(i) => Some(*i) == seat,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameMessage {
     pub action: GameAction,
     pub state: GameState,
+    pub view: SeatView,
 }
 
 impl GameMessage {
     pub fn new(action: GameAction, state: GameState) -> Self {
-        GameMessage { action, state }
+        let view = action.view();
+        GameMessage {
+            action,
+            state,
+            view,
+        }
     }
 }
 
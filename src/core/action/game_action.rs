@@ -1,12 +1,16 @@
+use serde::{Deserialize, Serialize};
+
 use crate::core::{
+    action::SeatView,
     card::Card,
+    clock::Countdown,
     error::EngineError,
     player::{PlayerAction, PlayerHand},
     rank::Rank,
     state::Round,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameAction {
     ///For the initial two cards
     DealStartHand { hand: PlayerHand, i: usize },
@@ -17,6 +21,12 @@ pub enum GameAction {
     ///Community card dealt
     DealCommunity { card: Card },
 
+    ///The engine asks the player to decide, with the time to do it when the player is timed
+    TurnStarted { i: usize, time: Option<Countdown> },
+
+    ///The player didn't decide in time, so the engine played for the player
+    TimedOut { i: usize, action: PlayerAction },
+
     ///Player bet
     PlayedBet {
         action: PlayerAction,
@@ -40,3 +50,17 @@ pub enum GameAction {
     ///Win game
     WinGame { rank: Rank, i: usize, pot: i32 },
 }
+
+impl GameAction {
+    ///Only the owner of the hand knows the cards dealt to them, the rest of the actions are public
+    pub fn view(&self) -> SeatView {
+        match self {
+            GameAction::DealStartHand { i, .. } => SeatView::Private(*i),
+            _ => SeatView::Public,
+        }
+    }
+
+    pub fn is_visible_to(&self, seat: usize) -> bool {
+        self.view().is_visible_to(Some(seat))
+    }
+}
@@ -0,0 +1,93 @@
+use super::{GameActionQueue, GameMessage};
+
+///Passes to another queue only the messages a seat is entitled to see
+#[derive(Debug)]
+pub struct SeatQueue {
+    inner: Box<dyn GameActionQueue>,
+    seat: Option<usize>,
+}
+
+impl GameActionQueue for SeatQueue {
+    fn add(&mut self, msg: GameMessage) {
+        if msg.view.is_visible_to(self.seat) {
+            self.inner.add(msg);
+        }
+    }
+}
+
+impl SeatQueue {
+    ///The public stream and the private stream of the seat
+    pub fn new(inner: Box<dyn GameActionQueue>, seat: usize) -> Self {
+        SeatQueue {
+            inner,
+            seat: Some(seat),
+        }
+    }
+
+    ///Only the public stream
+    pub fn spectator(inner: Box<dyn GameActionQueue>) -> Self {
+        SeatQueue { inner, seat: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use crate::core::{
+        action::{game_action::GameAction, mpsc_queue::MpscQueue, SeatView},
+        engine::Engine,
+        error::EngineError,
+        player::{dummy::DummyPlayer, Player},
+    };
+
+    use super::*;
+
+    fn play(seat: Option<usize>) -> Result<Vec<GameMessage>, EngineError> {
+        let (tx, rx) = mpsc::channel();
+        let queue = Box::new(MpscQueue::new(tx));
+        let queue = match seat {
+            Some(seat) => SeatQueue::new(queue, seat),
+            None => SeatQueue::spectator(queue),
+        };
+
+        let players = (0..4)
+            .map(|_| Box::<DummyPlayer>::default() as Box<dyn Player>)
+            .collect();
+        Engine::new(players, Box::new(queue))?.run(vec![100; 4], 1)?;
+
+        Ok(rx.try_iter().collect())
+    }
+
+    fn dealt_seats(messages: &[GameMessage]) -> Vec<usize> {
+        messages
+            .iter()
+            .filter_map(|msg| match msg.action {
+                GameAction::DealStartHand { i, .. } => Some(i),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn seat_stream() -> Result<(), EngineError> {
+        let messages = play(Some(2))?;
+
+        assert_eq!(dealt_seats(&messages), vec![2]);
+        assert!(messages
+            .iter()
+            .any(|msg| matches!(msg.action, GameAction::WinGame { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn spectator_stream() -> Result<(), EngineError> {
+        let messages = play(None)?;
+
+        assert!(dealt_seats(&messages).is_empty());
+        assert!(messages.iter().all(|msg| msg.view == SeatView::Public));
+
+        Ok(())
+    }
+}
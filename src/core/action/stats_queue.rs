@@ -0,0 +1,25 @@
+use crate::core::stats::SharedStats;
+
+use super::{GameActionQueue, GameMessage};
+
+///Updates the session statistics before passing the messages to another queue
+#[derive(Debug)]
+pub struct StatsQueue {
+    inner: Box<dyn GameActionQueue>,
+    stats: SharedStats,
+}
+
+impl GameActionQueue for StatsQueue {
+    fn add(&mut self, msg: GameMessage) {
+        if let Ok(mut stats) = self.stats.write() {
+            stats.update(&msg);
+        }
+        self.inner.add(msg);
+    }
+}
+
+impl StatsQueue {
+    pub fn new(inner: Box<dyn GameActionQueue>, stats: SharedStats) -> Self {
+        StatsQueue { inner, stats }
+    }
+}
@@ -1,4 +1,8 @@
-#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash)]
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum Suit {
     Club,
     Diamond,
@@ -6,9 +10,15 @@ pub enum Suit {
     Spade,
 }
 
+impl Suit {
+    pub fn symbol(&self) -> char {
+        "cdhs".as_bytes()[*self as usize] as char
+    }
+}
+
 pub const SUITS: [Suit; 4] = [Suit::Club, Suit::Diamond, Suit::Heart, Suit::Spade];
 
-#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum Value {
     Two,
     Three,
@@ -25,6 +35,12 @@ pub enum Value {
     Ace,
 }
 
+impl Value {
+    pub fn symbol(&self) -> char {
+        "23456789TJQKA".as_bytes()[*self as usize] as char
+    }
+}
+
 pub const VALUES: [Value; 13] = [
     Value::Ace,
     Value::Two,
@@ -41,7 +57,7 @@ pub const VALUES: [Value; 13] = [
     Value::King,
 ];
 
-#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone, Copy, Hash, Serialize, Deserialize)]
 pub struct Card {
     pub suit: Suit,
     pub value: Value,
@@ -52,3 +68,10 @@ impl Card {
         Card { suit, value }
     }
 }
+
+impl Display for Card {
+    ///The value and the suit, like Ah or Tc
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value.symbol(), self.suit.symbol())
+    }
+}
@@ -0,0 +1,260 @@
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    error::EngineError,
+    player::{Player, PlayerAction, PlayerHand},
+    state::GameState,
+};
+
+///Extra time for the players that stop by themselves when the deadline is reached
+const GRACE: Duration = Duration::from_millis(100);
+
+///Time a player has for every decision, after it the time bank of the player is spent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TimeLimit {
+    pub decision: Duration,
+    pub bank: Duration,
+}
+
+///The time of a decision. The deadline is a wall-clock time, so the time left is still right
+///when the event is read later
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Countdown {
+    pub total: Duration,
+    pub deadline: SystemTime,
+}
+
+impl Countdown {
+    ///Starts counting now
+    pub fn new(total: Duration) -> Self {
+        Countdown {
+            total,
+            deadline: SystemTime::now() + total,
+        }
+    }
+
+    pub fn left(&self) -> Duration {
+        self.deadline
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
+type Decision = (Box<dyn Player>, Result<PlayerAction, EngineError>);
+
+///Runs the decisions of the players that can block in other threads, so the engine doesn't wait
+///for them more than their time
+#[derive(Debug)]
+pub struct Clock {
+    limit: TimeLimit,
+    banks: Vec<Duration>,
+    ///The players that didn't answer in time and are still deciding
+    pending: Vec<Option<Receiver<Decision>>>,
+}
+
+impl Clock {
+    pub fn new(limit: TimeLimit, players: usize) -> Self {
+        Clock {
+            limit,
+            banks: vec![limit.bank; players],
+            pending: (0..players).map(|_| None).collect(),
+        }
+    }
+
+    ///The time the player has for the next decision, with their time bank
+    pub fn time_left(&self, i: usize) -> Duration {
+        self.limit.decision + self.bank(i)
+    }
+
+    pub fn bank(&self, i: usize) -> Duration {
+        self.banks.get(i).copied().unwrap_or_default()
+    }
+
+    ///Asks the player to decide, returning None if the time runs out
+    pub fn decide(
+        &mut self,
+        players: &mut [Box<dyn Player>],
+        i: usize,
+        state: &GameState,
+        blind: bool,
+    ) -> Option<Result<PlayerAction, EngineError>> {
+        //A player still busy with an old decision can't take this one
+        if let Some(rx) = self.pending[i].take() {
+            match rx.try_recv() {
+                Ok((mut player, _)) => {
+                    player.set_deadline(None);
+                    players[i] = player;
+                }
+                Err(TryRecvError::Empty) => {
+                    self.pending[i] = Some(rx);
+                    return None;
+                }
+                Err(TryRecvError::Disconnected) => return None,
+            }
+        }
+
+        let time = self.time_left(i);
+        let mut player = std::mem::replace(&mut players[i], Box::new(AbsentPlayer));
+        player.set_deadline(Some(Instant::now() + time));
+
+        let (tx, rx) = mpsc::channel();
+        let state = state.clone();
+        thread::spawn(move || {
+            let action = if blind {
+                player.blind(&state, i)
+            } else {
+                player.play(&state, i)
+            };
+            tx.send((player, action)).ok();
+        });
+
+        let start = Instant::now();
+        match rx.recv_timeout(time + GRACE) {
+            Ok((mut player, action)) => {
+                player.set_deadline(None);
+                players[i] = player;
+
+                let spent = start.elapsed().saturating_sub(self.limit.decision);
+                self.banks[i] = self.banks[i].saturating_sub(spent);
+
+                match action {
+                    Err(EngineError::TimeoutError) => None,
+                    action => Some(action),
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                self.pending[i] = Some(rx);
+                self.banks[i] = Duration::ZERO;
+                None
+            }
+            //The player panicked, an absent player is left in the seat
+            Err(RecvTimeoutError::Disconnected) => None,
+        }
+    }
+}
+
+///Takes the seat of a player that is still deciding in another thread, every decision times out
+#[derive(Debug)]
+struct AbsentPlayer;
+
+impl Player for AbsentPlayer {
+    fn cards(&self) -> Option<PlayerHand> {
+        None
+    }
+
+    fn give_cards(&mut self, _hand: PlayerHand) {}
+
+    fn blind(&mut self, _state: &GameState, _i: usize) -> Result<PlayerAction, EngineError> {
+        Err(EngineError::TimeoutError)
+    }
+
+    fn play(&mut self, _state: &GameState, _i: usize) -> Result<PlayerAction, EngineError> {
+        Err(EngineError::TimeoutError)
+    }
+
+    //The seat is still timed, so the old decision is collected by the clock
+    fn can_block(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        action::{game_action::GameAction, mpsc_queue::MpscQueue},
+        engine::Engine,
+        player::dummy::DummyPlayer,
+    };
+
+    use super::*;
+
+    ///Never answers in time
+    #[derive(Debug, Default)]
+    struct HungPlayer;
+
+    impl Player for HungPlayer {
+        fn cards(&self) -> Option<PlayerHand> {
+            None
+        }
+
+        fn give_cards(&mut self, _hand: PlayerHand) {}
+
+        fn blind(&mut self, _state: &GameState, _i: usize) -> Result<PlayerAction, EngineError> {
+            thread::sleep(Duration::from_secs(1));
+            Ok(PlayerAction::Fold)
+        }
+
+        fn play(&mut self, _state: &GameState, _i: usize) -> Result<PlayerAction, EngineError> {
+            thread::sleep(Duration::from_secs(1));
+            Ok(PlayerAction::Fold)
+        }
+
+        fn can_block(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn hung_player_times_out() -> Result<(), EngineError> {
+        let players = vec![
+            Box::<HungPlayer>::default() as Box<dyn Player>,
+            Box::<DummyPlayer>::default() as Box<dyn Player>,
+            Box::<DummyPlayer>::default() as Box<dyn Player>,
+        ];
+        let limit = TimeLimit {
+            decision: Duration::from_millis(50),
+            bank: Duration::from_millis(50),
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let start = Instant::now();
+        let stacks = Engine::new(players, Box::new(MpscQueue::new(tx)))?
+            .with_time_limit(limit)
+            .run(vec![100; 3], 1)?;
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(stacks.iter().sum::<i32>(), 300);
+
+        let timeouts: Vec<_> = rx
+            .try_iter()
+            .filter_map(|msg| match msg.action {
+                GameAction::TimedOut { i, action } => Some((i, action)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(timeouts, vec![(0, PlayerAction::Fold)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn time_bank() {
+        let limit = TimeLimit {
+            decision: Duration::from_millis(10),
+            bank: Duration::from_millis(100),
+        };
+        let mut clock = Clock::new(limit, 1);
+        let mut players = vec![Box::<HungPlayer>::default() as Box<dyn Player>];
+        let state = GameState {
+            round: crate::core::state::Round::Preflop,
+            community: vec![],
+            players_bet: vec![0],
+            players_money: vec![100],
+            bet_amount: 1,
+            players_all_in: vec![],
+            folded_players: vec![],
+            num_active_players: 1,
+            active_players: vec![0],
+        };
+
+        assert_eq!(clock.time_left(0), Duration::from_millis(110));
+        assert!(clock.decide(&mut players, 0, &state, true).is_none());
+        assert_eq!(clock.bank(0), Duration::ZERO);
+
+        //The player is still deciding the last action
+        assert!(clock.decide(&mut players, 0, &state, true).is_none());
+    }
+}
@@ -1,5 +1,6 @@
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, SeedableRng};
 
 use crate::core::card;
 
@@ -26,6 +27,31 @@ impl Deck {
         Deck { cards: v }
     }
 
+    ///A full deck shuffled from a seed, so the same cards are dealt every time
+    pub fn new_seeded(seed: u64) -> Self {
+        let mut deck = Deck { cards: Vec::new() };
+
+        for suit in card::SUITS {
+            for value in card::VALUES {
+                deck.cards.push(card::Card { suit, value });
+            }
+        }
+
+        deck.cards.shuffle(&mut StdRng::seed_from_u64(seed));
+
+        deck
+    }
+
+    ///A deck that deals the cards from the last one to the first one
+    pub fn from_cards(cards: Vec<card::Card>) -> Self {
+        Deck { cards }
+    }
+
+    ///The cards left, in the order of [`Deck::from_cards`]
+    pub fn cards(&self) -> &[card::Card] {
+        &self.cards
+    }
+
     ///Removes and retrieves a card from the deck
     pub fn take(&mut self) -> Option<card::Card> {
         self.cards.pop()
@@ -77,4 +103,15 @@ mod tests {
         let deck = Deck::new_without_cards(&c);
         println!("{:?}", deck);
     }
+
+    #[test]
+    fn seeded() {
+        let mut a = Deck::new_seeded(42);
+        let mut b = Deck::new_seeded(42);
+
+        assert_eq!(a.len(), 52);
+        while let Some(c) = a.take() {
+            assert_eq!(Some(c), b.take());
+        }
+    }
 }
@@ -0,0 +1,352 @@
+use rand::Rng;
+
+use crate::core::card::*;
+use crate::core::player::PlayerHand;
+
+///The kind of drawing hand a player is holding
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DrawKind {
+    #[default]
+    None,
+    ///Only one value completes the straight
+    Gutshot,
+    ///Two values complete the straight
+    OpenEnded,
+    ///Four cards of the same suit
+    Flush,
+    ///A flush draw and a straight draw at the same time
+    Combo,
+}
+
+///The straight and flush draws of a hand, with every card that completes them
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub kind: DrawKind,
+    pub outs: Vec<Card>,
+}
+
+impl Draw {
+    ///Computes the draws of the hand with the community cards, there are only draws in the flop and the turn
+    pub fn new(hand: PlayerHand, community: &[Card]) -> Self {
+        if community.len() < 3 || community.len() > 4 {
+            return Draw::default();
+        }
+
+        let mut known = community.to_vec();
+        known.push(hand.0);
+        known.push(hand.1);
+
+        let flush_suit = flush_draw_suit(hand, &known);
+        let straight_values = straight_draw_values(hand, community);
+
+        let mut outs = Vec::new();
+        for suit in SUITS {
+            for value in VALUES {
+                let c = Card::new(suit, value);
+                if !known.contains(&c)
+                    && (flush_suit == Some(suit) || straight_values.contains(&value))
+                {
+                    outs.push(c);
+                }
+            }
+        }
+
+        let kind = match (flush_suit.is_some(), straight_values.len()) {
+            (true, 0) => DrawKind::Flush,
+            (true, _) => DrawKind::Combo,
+            (false, 0) => DrawKind::None,
+            (false, 1) => DrawKind::Gutshot,
+            (false, _) => DrawKind::OpenEnded,
+        };
+
+        Draw { kind, outs }
+    }
+
+    pub fn is_drawing(&self) -> bool {
+        self.kind != DrawKind::None
+    }
+
+    ///Probability of hitting an out with the cards to come, using the rule of 2 and 4
+    pub fn equity(&self, community_len: usize) -> f64 {
+        let to_come = 5usize.saturating_sub(community_len);
+        (self.outs.len() * 2 * to_come) as f64 / 100.0
+    }
+}
+
+///How dangerous the community cards are for a player that hasn't a strong hand
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BoardTexture {
+    ///Two community cards share the value
+    pub paired: bool,
+    ///Three or more community cards share the suit
+    pub flush_possible: bool,
+    ///Three or more community cards fit in a straight
+    pub straight_possible: bool,
+    ///Community cards from a ten to an ace
+    pub high_cards: usize,
+}
+
+impl BoardTexture {
+    pub fn new(community: &[Card]) -> Self {
+        let paired = community
+            .iter()
+            .enumerate()
+            .any(|(i, a)| community[i + 1..].iter().any(|b| a.value == b.value));
+
+        let flush_possible = SUITS
+            .iter()
+            .any(|&s| community.iter().filter(|c| c.suit == s).count() >= 3);
+
+        let mask = value_mask(community);
+        let straight_possible = (0..=9).any(|s| (ace_low(mask) >> s & 0x1F).count_ones() >= 3);
+
+        let high_cards = community.iter().filter(|c| c.value >= Value::Ten).count();
+
+        BoardTexture {
+            paired,
+            flush_possible,
+            straight_possible,
+            high_cards,
+        }
+    }
+
+    ///A value between 0 and 1, the higher the more likely a bet represents a strong hand
+    pub fn scare(&self) -> f64 {
+        let mut scare = 0.0;
+        if self.flush_possible {
+            scare += 0.35;
+        }
+        if self.straight_possible {
+            scare += 0.25;
+        }
+        if self.paired {
+            scare += 0.2;
+        }
+        scare += 0.05 * self.high_cards as f64;
+
+        scare.min(1.0)
+    }
+}
+
+const SEMI_BLUFF_PROB: f64 = 0.6;
+const BLUFF_BET: f64 = 0.66;
+
+///The proportion of bluffs in a betting range that makes the opponent indifferent to call a bet
+pub fn bluff_frequency(pot: i32, bet: i32) -> f64 {
+    if bet <= 0 {
+        return 0.0;
+    }
+
+    bet as f64 / (pot + 2 * bet) as f64
+}
+
+///Decides if a player with a weak hand should bet anyway, returning the amount to raise over the call
+///<br>Draws are semi-bluffed and scary boards are bluffed with a balanced frequency
+pub fn bluff_raise<R: Rng>(
+    rng: &mut R,
+    hand: PlayerHand,
+    community: &[Card],
+    pot: i32,
+    diff: i32,
+    cash: i32,
+) -> Option<i32> {
+    if community.len() < 3 || cash <= diff {
+        return None;
+    }
+
+    let pot = pot.max(1);
+    let draw = Draw::new(hand, community);
+    let texture = BoardTexture::new(community);
+
+    let (prob, size) = if draw.is_drawing() {
+        let equity = draw.equity(community.len()).min(1.0);
+        (SEMI_BLUFF_PROB * equity.max(0.2), 0.5 + equity / 2.0)
+    } else {
+        let bet = (pot as f64 * BLUFF_BET) as i32;
+        (bluff_frequency(pot, bet) * texture.scare(), BLUFF_BET)
+    };
+
+    if !rng.gen_bool(prob.clamp(0.0, 1.0)) {
+        return None;
+    }
+
+    let raised = ((pot as f64 * size) as i32).clamp(1, cash - diff);
+    Some(raised)
+}
+
+///The suit of the flush draw, if one of the hand cards has it and there are exactly four of it
+fn flush_draw_suit(hand: PlayerHand, known: &[Card]) -> Option<Suit> {
+    SUITS.into_iter().find(|&s| {
+        (hand.0.suit == s || hand.1.suit == s) && known.iter().filter(|c| c.suit == s).count() == 4
+    })
+}
+
+///The values that would complete a straight using at least one card of the hand
+fn straight_draw_values(hand: PlayerHand, community: &[Card]) -> Vec<Value> {
+    let board = value_mask(community);
+    let all = board | value_mask(&[hand.0, hand.1]);
+
+    if has_straight(all) {
+        return Vec::new();
+    }
+
+    VALUES
+        .into_iter()
+        .filter(|&v| {
+            let bit = 1 << v as u16;
+            has_straight(all | bit) && !has_straight(board | bit)
+        })
+        .collect()
+}
+
+fn value_mask(cards: &[Card]) -> u16 {
+    cards.iter().fold(0, |m, c| m | 1 << c.value as u16)
+}
+
+///Shifts the mask so the ace can also be used as the lowest card
+fn ace_low(mask: u16) -> u16 {
+    (mask << 1) | (mask >> Value::Ace as u16 & 1)
+}
+
+fn has_straight(mask: u16) -> bool {
+    let m = ace_low(mask);
+    (0..=9).any(|s| m >> s & 0x1F == 0x1F)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(suit: Suit, value: Value) -> Card {
+        Card::new(suit, value)
+    }
+
+    #[test]
+    fn flush_draw_outs() {
+        let hand = (c(Suit::Heart, Value::Ace), c(Suit::Heart, Value::Seven));
+        let community = [
+            c(Suit::Heart, Value::King),
+            c(Suit::Heart, Value::Two),
+            c(Suit::Club, Value::Nine),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::Flush);
+        assert_eq!(draw.outs.len(), 9);
+        assert!(draw.outs.iter().all(|c| c.suit == Suit::Heart));
+    }
+
+    #[test]
+    fn open_ended_outs() {
+        let hand = (c(Suit::Heart, Value::Eight), c(Suit::Club, Value::Seven));
+        let community = [
+            c(Suit::Spade, Value::Six),
+            c(Suit::Diamond, Value::Five),
+            c(Suit::Club, Value::King),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::OpenEnded);
+        assert_eq!(draw.outs.len(), 8);
+    }
+
+    #[test]
+    fn gutshot_outs() {
+        let hand = (c(Suit::Heart, Value::Ace), c(Suit::Club, Value::King));
+        let community = [
+            c(Suit::Spade, Value::Queen),
+            c(Suit::Diamond, Value::Ten),
+            c(Suit::Club, Value::Three),
+            c(Suit::Heart, Value::Two),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::Gutshot);
+        assert_eq!(draw.outs.len(), 4);
+        assert!(draw.outs.iter().all(|c| c.value == Value::Jack));
+    }
+
+    #[test]
+    fn wheel_gutshot_outs() {
+        let hand = (c(Suit::Heart, Value::Ace), c(Suit::Club, Value::Two));
+        let community = [
+            c(Suit::Spade, Value::Three),
+            c(Suit::Diamond, Value::Five),
+            c(Suit::Club, Value::Jack),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::Gutshot);
+        assert!(draw.outs.iter().all(|c| c.value == Value::Four));
+    }
+
+    #[test]
+    fn combo_draw_outs() {
+        let hand = (c(Suit::Spade, Value::Nine), c(Suit::Spade, Value::Eight));
+        let community = [
+            c(Suit::Spade, Value::Seven),
+            c(Suit::Spade, Value::Six),
+            c(Suit::Heart, Value::Two),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::Combo);
+        //9 spades plus the 3 tens and 3 fives that aren't spades
+        assert_eq!(draw.outs.len(), 15);
+    }
+
+    #[test]
+    fn board_straight_not_counted() {
+        let hand = (c(Suit::Heart, Value::Two), c(Suit::Club, Value::Two));
+        let community = [
+            c(Suit::Spade, Value::Nine),
+            c(Suit::Diamond, Value::Eight),
+            c(Suit::Club, Value::Seven),
+            c(Suit::Heart, Value::Six),
+        ];
+
+        let draw = Draw::new(hand, &community);
+        assert_eq!(draw.kind, DrawKind::None);
+        assert!(draw.outs.is_empty());
+    }
+
+    #[test]
+    fn no_draws_on_river() {
+        let hand = (c(Suit::Heart, Value::Ace), c(Suit::Heart, Value::Seven));
+        let community = [
+            c(Suit::Heart, Value::King),
+            c(Suit::Heart, Value::Two),
+            c(Suit::Club, Value::Nine),
+            c(Suit::Club, Value::Four),
+            c(Suit::Diamond, Value::Four),
+        ];
+
+        assert!(!Draw::new(hand, &community).is_drawing());
+    }
+
+    #[test]
+    fn scary_board() {
+        let dry = BoardTexture::new(&[
+            c(Suit::Heart, Value::King),
+            c(Suit::Club, Value::Seven),
+            c(Suit::Diamond, Value::Two),
+        ]);
+        let wet = BoardTexture::new(&[
+            c(Suit::Heart, Value::Jack),
+            c(Suit::Heart, Value::Ten),
+            c(Suit::Heart, Value::Nine),
+            c(Suit::Club, Value::Nine),
+        ]);
+
+        assert!(!dry.flush_possible && !dry.straight_possible && !dry.paired);
+        assert!(wet.flush_possible && wet.straight_possible && wet.paired);
+        assert!(dry.scare() < wet.scare());
+    }
+
+    #[test]
+    fn balanced_bluff_frequency() {
+        assert_eq!(bluff_frequency(100, 100), 1.0 / 3.0);
+        assert_eq!(bluff_frequency(100, 50), 0.25);
+        assert_eq!(bluff_frequency(100, 0), 0.0);
+    }
+}
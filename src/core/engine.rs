@@ -9,9 +9,13 @@ use crate::core::rank::Rankable;
 use crate::core::state::*;
 
 use super::action::game_action::GameAction;
+use super::action::stats_queue::StatsQueue;
 use super::action::GameActionQueue;
 use super::action::GameMessage;
+use super::clock::{Clock, Countdown, TimeLimit};
 use super::error::EngineError;
+use super::snapshot::{SharedSnapshot, Snapshot};
+use super::stats::SharedStats;
 
 #[derive(Debug)]
 pub struct Engine {
@@ -20,6 +24,9 @@ pub struct Engine {
     pub state: GameState,
     pub players_hands: Vec<(Card, Card)>,
     pub players: Vec<Box<dyn Player>>,
+    clock: Option<Clock>,
+    snapshots: Option<SharedSnapshot>,
+    resume_point: Option<(Vec<usize>, bool)>,
 }
 
 impl Engine {
@@ -48,9 +55,43 @@ impl Engine {
             state,
             players_hands,
             players,
+            clock: None,
+            snapshots: None,
+            resume_point: None,
         })
     }
 
+    ///Creates an engine that keeps the session statistics updated and shares them with the players
+    pub fn with_stats(
+        mut players: Vec<Box<dyn Player>>,
+        action_queue: Box<dyn GameActionQueue>,
+        stats: SharedStats,
+    ) -> Result<Self, EngineError> {
+        for p in players.iter_mut() {
+            p.track_stats(stats.clone());
+        }
+
+        Engine::new(players, Box::new(StatsQueue::new(action_queue, stats)))
+    }
+
+    ///Deals the cards from a deck shuffled with the seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.deck = Deck::new_seeded(seed);
+        self
+    }
+
+    ///Limits the time of every decision, the players that run out of time check or fold
+    pub fn with_time_limit(mut self, limit: TimeLimit) -> Self {
+        self.clock = Some(Clock::new(limit, self.players.len()));
+        self
+    }
+
+    ///Keeps in the slot a snapshot of the hand before every decision, it's emptied when the hand ends
+    pub fn with_snapshots(mut self, slot: SharedSnapshot) -> Self {
+        self.snapshots = Some(slot);
+        self
+    }
+
     pub fn run(mut self, players_money: Vec<i32>, blind: i32) -> Result<Vec<i32>, EngineError> {
         if self.players.len() != players_money.len() {
             return Err(EngineError::BadGameError);
@@ -64,22 +105,83 @@ impl Engine {
             self.state.bet_amount = blind;
         }
 
+        self.play_rounds(0)
+    }
+
+    ///Continues the hand from the decision where the snapshot was taken, with [`Engine::run_resumed`]
+    pub fn resume_from(mut self, snapshot: Snapshot) -> Result<Self, EngineError> {
+        if self.players.len() != snapshot.hands.len()
+            || self.players.len() != snapshot.state.players_money.len()
+        {
+            return Err(EngineError::BadGameError);
+        }
+
+        self.deck = Deck::from_cards(snapshot.deck);
+        self.state = snapshot.state;
+        self.players_hands = snapshot.hands;
+        self.resume_point = Some((snapshot.pending, snapshot.raising));
+
+        Ok(self)
+    }
+
+    ///Plays the rest of a resumed hand
+    pub fn run_resumed(mut self) -> Result<Vec<i32>, EngineError> {
+        let (pending, raising) = self.resume_point.take().ok_or(EngineError::BadGameError)?;
+
+        //The players and the queue receive the cards dealt before
+        self.start_hand();
+        for i in 0..self.players.len() {
+            let hand = self.players_hands[i];
+            self.players[i].give_cards(hand);
+            self.add_action(GameAction::DealStartHand { hand, i });
+        }
+        self.add_action(GameAction::RoundChanged {
+            round: self.state.round,
+        });
+        for card in self.state.community.clone() {
+            self.add_action(GameAction::DealCommunity { card });
+        }
+
+        match self.state.round {
+            Round::Preflop => {
+                let start = pending.first().copied().unwrap_or(self.players.len());
+                self.preflop_from(start)?;
+            }
+            Round::Flop | Round::Turn | Round::River => {
+                self.betting_pass(pending, raising)?;
+                self.state.round = self.state.round.next();
+            }
+            _ => {}
+        }
+
+        self.play_rounds(0)
+    }
+
+    fn play_rounds(&mut self, preflop_start: usize) -> Result<Vec<i32>, EngineError> {
         loop {
             match self.state.round {
                 Round::Starting => self.start()?,
-                Round::Preflop => self.preflop(0)?,
+                Round::Preflop => self.preflop(preflop_start)?,
                 Round::Flop => self.flop()?,
                 Round::Turn => self.turn()?,
                 Round::River => self.river()?,
                 Round::Showdown => self.showdown()?,
 
-                Round::Complete => return Ok(self.state.players_money),
+                Round::Complete => {
+                    self.end_hand();
+                    if let Some(slot) = &self.snapshots {
+                        *slot.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                    }
+                    return Ok(self.state.players_money.clone());
+                }
             };
         }
     }
 
     fn start(&mut self) -> Result<(), EngineError> {
-        for (i, p) in self.players.iter_mut().enumerate() {
+        self.start_hand();
+
+        for i in 0..self.players.len() {
             self.state.players_bet.push(0);
 
             let hand = (
@@ -87,12 +189,9 @@ impl Engine {
                 self.deck.take().ok_or(EngineError::BadDeckError)?,
             );
             self.players_hands.push(hand);
-            p.give_cards(hand);
+            self.players[i].give_cards(hand);
 
-            self.action_queue.add(GameMessage::new(
-                GameAction::DealStartHand { hand, i },
-                self.state.clone(),
-            ));
+            self.add_action(GameAction::DealStartHand { hand, i });
         }
 
         self.state.round = self.state.round.next();
@@ -104,13 +203,18 @@ impl Engine {
             round: self.state.round,
         });
 
+        self.preflop_from(start)
+    }
+
+    fn preflop_from(&mut self, start: usize) -> Result<(), EngineError> {
         for i in start..self.players.len() {
             //If there's only one player, there's no need to play
             if self.state.num_active_players <= 1 {
                 break;
             }
 
-            let action = self.players[i].blind(&self.state, i);
+            self.take_snapshot((i..self.players.len()).collect(), false);
+            let action = self.decide(i, true);
 
             match action {
                 Ok(PlayerAction::Call(amount)) => {
@@ -229,19 +333,22 @@ impl Engine {
     }
 
     fn betting_round(&mut self) -> Result<(), EngineError> {
-        let mut raising = true;
-
-        while raising && self.state.num_active_players > 1 {
-            raising = false;
+        let pass = self.state.active_players.clone();
+        self.betting_pass(pass, false)
+    }
 
-            for i in self.state.active_players.clone() {
+    ///Plays the seats left in this pass of the betting round, and more passes while someone raises
+    fn betting_pass(&mut self, mut pass: Vec<usize>, mut raising: bool) -> Result<(), EngineError> {
+        loop {
+            for (k, &i) in pass.iter().enumerate() {
                 //If there's only one player, there's no need to play
                 if self.state.num_active_players <= 1 {
                     self.state.remove_inactive_players();
                     return Ok(());
                 }
 
-                let action = self.players[i].play(&self.state, i);
+                self.take_snapshot(pass[k..].to_vec(), raising);
+                let action = self.decide(i, false);
 
                 match action {
                     Ok(PlayerAction::Raise(amount)) => {
@@ -287,9 +394,13 @@ impl Engine {
                     }
                 }
             }
-        }
 
-        Ok(())
+            if !raising || self.state.num_active_players <= 1 {
+                return Ok(());
+            }
+            raising = false;
+            pass = self.state.active_players.clone();
+        }
     }
 
     fn deal_community(&mut self, n: i32) -> Result<(), EngineError> {
@@ -303,12 +414,80 @@ impl Engine {
         Ok(())
     }
 
+    ///Asks the player for the decision, the queue is told first so the UI knows whose turn it is.
+    ///<br>Only the players that can block are timed
+    fn decide(&mut self, i: usize, blind: bool) -> Result<PlayerAction, EngineError> {
+        let time = match &self.clock {
+            Some(clock) if self.players[i].can_block() => Some(Countdown::new(clock.time_left(i))),
+            _ => None,
+        };
+        self.add_action(GameAction::TurnStarted { i, time });
+        if time.is_none() {
+            return if blind {
+                self.players[i].blind(&self.state, i)
+            } else {
+                self.players[i].play(&self.state, i)
+            };
+        }
+
+        let decision = match &mut self.clock {
+            Some(clock) => clock.decide(&mut self.players, i, &self.state, blind),
+            None => None,
+        };
+        match decision {
+            Some(action) => action,
+            None => {
+                //Check if there's nothing to call, fold otherwise
+                let action = if self.state.bet_amount <= self.state.players_bet[i] {
+                    PlayerAction::Call(0)
+                } else {
+                    PlayerAction::Fold
+                };
+                self.add_action(GameAction::TimedOut { i, action });
+
+                Ok(action)
+            }
+        }
+    }
+
+    ///Keeps the state before the decision of the first pending seat, so the hand can be resumed
+    fn take_snapshot(&mut self, pending: Vec<usize>, raising: bool) {
+        if let Some(slot) = &self.snapshots {
+            let snapshot = Snapshot {
+                state: self.state.clone(),
+                deck: self.deck.cards().to_vec(),
+                hands: self.players_hands.clone(),
+                pending,
+                raising,
+            };
+            *slot.lock().unwrap_or_else(|e| e.into_inner()) = Some(snapshot);
+        }
+    }
+
     #[inline(always)]
     fn add_action(&mut self, action: GameAction) {
+        for (i, p) in self.players.iter_mut().enumerate() {
+            if action.is_visible_to(i) {
+                p.observe(&action, &self.state);
+            }
+        }
+
         self.action_queue
             .add(GameMessage::new(action, self.state.clone()));
     }
 
+    fn start_hand(&mut self) {
+        for (i, p) in self.players.iter_mut().enumerate() {
+            p.start_hand(i, &self.state);
+        }
+    }
+
+    fn end_hand(&mut self) {
+        for p in self.players.iter_mut() {
+            p.end_hand(&self.state);
+        }
+    }
+
     pub fn run_from_game_state(
         players: Vec<Box<dyn Player>>,
         action_queue: Box<dyn GameActionQueue>,
@@ -326,8 +505,12 @@ impl Engine {
             state,
             players_hands,
             players,
+            clock: None,
+            snapshots: None,
+            resume_point: None,
         };
 
+        engine.start_hand();
         for i in 0..engine.players.len() {
             if i == player_idx {
                 engine.players_hands.push(hand);
@@ -340,7 +523,7 @@ impl Engine {
                 );
                 engine.players_hands.push(h);
                 engine.players[i].give_cards(h);
-                engine.add_action(GameAction::DealStartHand { hand, i });
+                engine.add_action(GameAction::DealStartHand { hand: h, i });
             }
         }
 
@@ -352,18 +535,7 @@ impl Engine {
             _ => (),
         }
 
-        loop {
-            match engine.state.round {
-                Round::Starting => engine.start()?,
-                Round::Preflop => engine.preflop(player_idx)?,
-                Round::Flop => engine.flop()?,
-                Round::Turn => engine.turn()?,
-                Round::River => engine.river()?,
-                Round::Showdown => engine.showdown()?,
-
-                Round::Complete => return Ok(engine.state.players_money),
-            };
-        }
+        engine.play_rounds(player_idx)
     }
 }
 
@@ -423,6 +595,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn every_table_size() -> Result<(), EngineError> {
+        INIT.call_once(env_logger::init);
+
+        for n in 2..=10 {
+            let players = (0..n)
+                .map(|_| Box::<dummy::DummyPlayer>::default() as Box<dyn Player>)
+                .collect();
+            let engine = Engine::new(players, Box::new(TestQueue::default()))?;
+
+            let stacks = engine.run(vec![1000; n], 1)?;
+            assert_eq!(stacks.len(), n);
+            assert_eq!(stacks.iter().sum::<i32>(), 1000 * n as i32);
+        }
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "Takes ages for running"]
     fn loop_dummies() -> Result<(), EngineError> {
@@ -464,6 +654,156 @@ mod tests {
         Ok(())
     }
 
+    #[derive(Debug, Default)]
+    struct ObserverPlayer {
+        dummy: dummy::DummyPlayer,
+        log: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl Player for ObserverPlayer {
+        fn cards(&self) -> Option<PlayerHand> {
+            self.dummy.cards()
+        }
+
+        fn give_cards(&mut self, hand: PlayerHand) {
+            self.dummy.give_cards(hand)
+        }
+
+        fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            self.dummy.blind(state, i)
+        }
+
+        fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            self.dummy.play(state, i)
+        }
+
+        fn start_hand(&mut self, i: usize, _state: &GameState) {
+            self.log.lock().unwrap().push(format!("start {i}"));
+        }
+
+        fn observe(&mut self, action: &GameAction, _state: &GameState) {
+            let event = match action {
+                GameAction::DealStartHand { i, .. } => format!("deal {i}"),
+                GameAction::WinGame { .. } => "win".to_string(),
+                _ => "event".to_string(),
+            };
+            self.log.lock().unwrap().push(event);
+        }
+
+        fn end_hand(&mut self, _state: &GameState) {
+            self.log.lock().unwrap().push("end".to_string());
+        }
+    }
+
+    #[test]
+    fn observe_events() -> Result<(), EngineError> {
+        INIT.call_once(env_logger::init);
+
+        let logs = (0..4).map(|_| Default::default()).collect::<Vec<_>>();
+        let players = logs
+            .iter()
+            .map(|log| {
+                Box::new(ObserverPlayer {
+                    dummy: Default::default(),
+                    log: std::sync::Arc::clone(log),
+                }) as Box<dyn Player>
+            })
+            .collect();
+
+        let engine = Engine::new(players, Box::new(EmptyQueue::default()))?;
+        engine.run(vec![100; 4], 1)?;
+
+        for (i, log) in logs.iter().enumerate() {
+            let log = log.lock().unwrap();
+            assert_eq!(log.first(), Some(&format!("start {i}")));
+            assert_eq!(log.last(), Some(&"end".to_string()));
+            assert!(log.contains(&"win".to_string()));
+
+            //Only the own cards of the player are seen
+            let deals = log
+                .iter()
+                .filter(|e| e.starts_with("deal"))
+                .collect::<Vec<_>>();
+            assert_eq!(deals, vec![&format!("deal {i}")]);
+        }
+
+        Ok(())
+    }
+
+    ///Raises once at the flop, calls the rest of the hand
+    #[derive(Debug, Default)]
+    struct FlopRaiser {
+        hand: Option<PlayerHand>,
+        raised: bool,
+    }
+
+    impl Player for FlopRaiser {
+        fn cards(&self) -> Option<PlayerHand> {
+            self.hand
+        }
+
+        fn give_cards(&mut self, hand: PlayerHand) {
+            self.hand = Some(hand);
+        }
+
+        fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            Ok(PlayerAction::Call(state.bet_amount - state.players_bet[i]))
+        }
+
+        fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            let diff = state.bet_amount - state.players_bet[i];
+            if state.round == Round::Flop && !self.raised {
+                self.raised = true;
+                return Ok(PlayerAction::Raise(diff + 10));
+            }
+            Ok(PlayerAction::Call(diff))
+        }
+    }
+
+    #[test]
+    fn raise_asks_the_first_seat_again() -> Result<(), EngineError> {
+        let (action_tx, action_rx) = std::sync::mpsc::channel();
+        for _ in 0..10 {
+            action_tx.send(crate::game::player_state::PlayerAction::Call).unwrap();
+        }
+        let players = vec![
+            Box::new(myself::MyselfPlayer::new(action_rx)) as Box<dyn Player>,
+            Box::<FlopRaiser>::default() as Box<dyn Player>,
+        ];
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let queue = Box::new(crate::core::action::mpsc_queue::MpscQueue::new(tx));
+        let stacks = Engine::new(players, queue)?.run(vec![100; 2], 1)?;
+        assert_eq!(stacks.iter().sum::<i32>(), 200);
+
+        //Every decision at the flop is announced, the first seat is asked again after the raise
+        let flop = rx
+            .try_iter()
+            .skip_while(|msg| !matches!(msg.action, GameAction::RoundChanged { round: Round::Flop }))
+            .take_while(|msg| !matches!(msg.action, GameAction::RoundChanged { round: Round::Turn }))
+            .filter_map(|msg| match msg.action {
+                GameAction::TurnStarted { i, .. } => Some(format!("asked {i}")),
+                GameAction::PlayedBet { action, i, .. } => Some(format!("{i} {action:?}")),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            flop,
+            [
+                "asked 0",
+                "0 Call(0)",
+                "asked 1",
+                "1 Raise(10)",
+                "asked 0",
+                "0 Call(10)",
+                "asked 1",
+                "1 Call(0)",
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn run_from_started_game() {
         INIT.call_once(env_logger::init);
@@ -507,4 +847,47 @@ mod tests {
         .unwrap();
         println!("{stacks:?}");
     }
+
+    #[test]
+    fn run_from_game_state_deals() -> Result<(), EngineError> {
+        //The hand is resumed before the blinds
+        let state = crate::core::state::GameState {
+            round: Round::Preflop,
+            community: vec![],
+            players_bet: vec![0; 3],
+            players_money: vec![100; 3],
+            bet_amount: 0,
+            players_all_in: vec![],
+            folded_players: vec![],
+            num_active_players: 3,
+            active_players: vec![0, 1, 2],
+        };
+        let players = (0..3)
+            .map(|_| Box::new(dummy::DummyPlayer::default()) as Box<dyn Player>)
+            .collect();
+        let hand = (
+            Card::new(Suit::Club, Value::Ace),
+            Card::new(Suit::Spade, Value::Ace),
+        );
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let queue = Box::new(crate::core::action::mpsc_queue::MpscQueue::new(tx));
+        let stacks = Engine::run_from_game_state(players, queue, state, hand, 1)?;
+        assert_eq!(stacks.iter().sum::<i32>(), 300);
+
+        //Every seat is dealt its own cards, only once
+        let hands: Vec<_> = rx
+            .try_iter()
+            .filter_map(|msg| match msg.action {
+                GameAction::DealStartHand { hand, i } => Some((i, hand)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(hands.len(), 3);
+        assert_eq!(hands[1], (1, hand));
+        assert_ne!(hands[0].1, hand);
+        assert_ne!(hands[2].1, hand);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,319 @@
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+
+use crate::core::{
+    action::{game_action::GameAction, mpsc_queue::MpscQueue, seat_queue::SeatQueue, GameMessage},
+    card::Card,
+    engine::Engine,
+    error::EngineError,
+    player::{agent::AgentPlayer, dummy::DummyPlayer, Player, PlayerAction, PlayerHand},
+    state::{GameState, Round},
+};
+use crate::game::player_state;
+
+///Maximum number of seats that fit in an observation
+pub const MAX_SEATS: usize = 10;
+///Number of past actions in an observation
+pub const HISTORY_LEN: usize = 16;
+
+const CARD_FEATURES: usize = 2 * 52;
+const ROUND_FEATURES: usize = 4;
+const SEAT_FEATURES: usize = 5;
+const POT_FEATURES: usize = 2;
+const ACTION_FEATURES: usize = 5;
+
+pub const OBSERVATION_SIZE: usize = CARD_FEATURES
+    + ROUND_FEATURES
+    + MAX_SEATS * SEAT_FEATURES
+    + POT_FEATURES
+    + HISTORY_LEN * ACTION_FEATURES;
+
+///Numeric encoding of what the agent can see, with every amount relative to the chips in play
+pub type Observation = [f32; OBSERVATION_SIZE];
+
+///Builds the players that sit in every seat but the agent's one
+pub type OpponentFactory = Arc<dyn Fn(usize) -> Box<dyn Player> + Send + Sync>;
+
+///A poker hand driven step by step, every episode is a single hand against the opponents
+pub struct PokerEnv {
+    seat: usize,
+    stacks: Vec<i32>,
+    blind: i32,
+    opponents: OpponentFactory,
+    episode: Option<Episode>,
+}
+
+struct Episode {
+    seat: usize,
+    decision_rx: mpsc::Receiver<(GameState, usize)>,
+    action_tx: mpsc::Sender<player_state::PlayerAction>,
+    game_rx: mpsc::Receiver<GameMessage>,
+    engine: Option<JoinHandle<Result<Vec<i32>, EngineError>>>,
+
+    state: Option<GameState>,
+    hand: Option<PlayerHand>,
+    history: Vec<(usize, PlayerAction)>,
+    done: bool,
+}
+
+impl PokerEnv {
+    ///The agent plays in the seat against dummy players, every seat starts with the stack
+    pub fn new(players: usize, seat: usize, stack: i32, blind: i32) -> Result<Self, EngineError> {
+        if !(2..=MAX_SEATS).contains(&players) || seat >= players {
+            return Err(EngineError::BadGameError);
+        }
+
+        Ok(PokerEnv {
+            seat,
+            stacks: vec![stack; players],
+            blind,
+            opponents: Arc::new(|_| Box::<DummyPlayer>::default()),
+            episode: None,
+        })
+    }
+
+    pub fn with_opponents(mut self, opponents: OpponentFactory) -> Self {
+        self.opponents = opponents;
+        self
+    }
+
+    ///Starts a new hand with the cards shuffled from the seed, returning the first observation
+    pub fn reset(&mut self, seed: u64) -> Result<Observation, EngineError> {
+        let (decision_tx, decision_rx) = mpsc::channel();
+        let (action_tx, action_rx) = mpsc::channel();
+        let (game_tx, game_rx) = mpsc::channel();
+
+        let seat = self.seat;
+        let stacks = self.stacks.clone();
+        let blind = self.blind;
+        let opponents = self.opponents.clone();
+
+        let engine = thread::spawn(move || {
+            let mut players = (0..stacks.len()).map(|i| opponents(i)).collect::<Vec<_>>();
+            players[seat] = Box::new(AgentPlayer::new(decision_tx, action_rx));
+
+            let queue = Box::new(SeatQueue::new(Box::new(MpscQueue::new(game_tx)), seat));
+            Engine::new(players, queue)?
+                .with_seed(seed)
+                .run(stacks, blind)
+        });
+
+        self.episode = Some(Episode {
+            seat,
+            decision_rx,
+            action_tx,
+            game_rx,
+            engine: Some(engine),
+            state: None,
+            hand: None,
+            history: Vec::new(),
+            done: false,
+        });
+
+        self.wait().map(|(observation, _)| observation)
+    }
+
+    ///Plays the action for the agent and runs the hand until the agent has to play again
+    ///<br>The reward is the money won or lost, given only when the hand is done
+    pub fn step(
+        &mut self,
+        action: player_state::PlayerAction,
+    ) -> Result<(Observation, f32, bool), EngineError> {
+        let episode = self.episode.as_mut().ok_or(EngineError::HandEndedError)?;
+        if episode.done {
+            return Err(EngineError::HandEndedError);
+        }
+
+        episode
+            .action_tx
+            .send(action)
+            .map_err(|_| EngineError::RecvMyselfError)?;
+
+        let (observation, reward) = self.wait()?;
+        Ok((observation, reward, self.is_done()))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.episode.as_ref().map(|e| e.done).unwrap_or(true)
+    }
+
+    ///Waits for the next decision of the agent or the end of the hand
+    fn wait(&mut self) -> Result<(Observation, f32), EngineError> {
+        let seat = self.seat;
+        let episode = self.episode.as_mut().ok_or(EngineError::HandEndedError)?;
+
+        let mut reward = 0.0;
+        match episode.decision_rx.recv() {
+            Ok((state, _)) => {
+                episode.receive_messages();
+                episode.state = Some(state);
+            }
+            Err(_) => {
+                //The agent is dropped with the engine, so the hand is over
+                let stacks = episode
+                    .engine
+                    .take()
+                    .ok_or(EngineError::HandEndedError)?
+                    .join()
+                    .map_err(|_| EngineError::GameNotCompletedSuccessfully)??;
+
+                episode.receive_messages();
+                episode.done = true;
+                reward = (stacks[seat] - self.stacks[seat]) as f32;
+            }
+        }
+
+        let state = episode.state.as_ref().ok_or(EngineError::BadGameError)?;
+        let observation = encode(state, episode.hand, seat, &episode.history);
+
+        Ok((observation, reward))
+    }
+}
+
+impl Episode {
+    fn receive_messages(&mut self) {
+        while let Ok(msg) = self.game_rx.try_recv() {
+            match msg.action {
+                GameAction::DealStartHand { hand, i } if i == self.seat => {
+                    self.hand = Some(hand);
+                }
+                GameAction::PlayedBet { action, i, .. }
+                | GameAction::PlayedFolded { action, i } => {
+                    self.history.push((i, action));
+                }
+                GameAction::ErroredPlay { i, .. } => self.history.push((i, PlayerAction::Fold)),
+                _ => {}
+            }
+            self.state = Some(msg.state);
+        }
+    }
+}
+
+///Encodes the state seen from the seat as a fixed size vector
+pub fn encode(
+    state: &GameState,
+    hand: Option<PlayerHand>,
+    seat: usize,
+    history: &[(usize, PlayerAction)],
+) -> Observation {
+    let mut obs = [0.0; OBSERVATION_SIZE];
+
+    let chips: i32 =
+        state.players_money.iter().sum::<i32>() + state.players_bet.iter().sum::<i32>();
+    let chips = chips.max(1) as f32;
+
+    //Cards
+    if let Some(hand) = hand {
+        obs[card_index(hand.0)] = 1.0;
+        obs[card_index(hand.1)] = 1.0;
+    }
+    for &c in &state.community {
+        obs[52 + card_index(c)] = 1.0;
+    }
+    let mut k = CARD_FEATURES;
+
+    //Round
+    let round = match state.round {
+        Round::Preflop => Some(0),
+        Round::Flop => Some(1),
+        Round::Turn => Some(2),
+        Round::River => Some(3),
+        _ => None,
+    };
+    if let Some(r) = round {
+        obs[k + r] = 1.0;
+    }
+    k += ROUND_FEATURES;
+
+    //Seats, starting from the agent
+    let n = state.players_money.len();
+    for s in 0..n.min(MAX_SEATS) {
+        let i = (seat + s) % n;
+        obs[k] = state.players_money[i] as f32 / chips;
+        obs[k + 1] = state.players_bet.get(i).copied().unwrap_or(0) as f32 / chips;
+        obs[k + 2] = state.folded_players.contains(&i) as i32 as f32;
+        obs[k + 3] = state.players_all_in.contains(&i) as i32 as f32;
+        obs[k + 4] = 1.0; //The seat is taken
+        k += SEAT_FEATURES;
+    }
+    k += (MAX_SEATS - n.min(MAX_SEATS)) * SEAT_FEATURES;
+
+    //Pot and amount to call
+    let my_bet = state.players_bet.get(seat).copied().unwrap_or(0);
+    obs[k] = state.players_bet.iter().sum::<i32>() as f32 / chips;
+    obs[k + 1] = (state.bet_amount - my_bet).max(0) as f32 / chips;
+    k += POT_FEATURES;
+
+    //Last actions, the most recent first
+    for &(i, action) in history.iter().rev().take(HISTORY_LEN) {
+        obs[k] = ((i + n - seat) % n.max(1)) as f32 / MAX_SEATS as f32;
+        match action {
+            PlayerAction::Fold => obs[k + 1] = 1.0,
+            PlayerAction::Call(a) => {
+                obs[k + 2] = 1.0;
+                obs[k + 4] = a as f32 / chips;
+            }
+            PlayerAction::Raise(a) => {
+                obs[k + 3] = 1.0;
+                obs[k + 4] = a as f32 / chips;
+            }
+        }
+        k += ACTION_FEATURES;
+    }
+
+    obs
+}
+
+fn card_index(c: Card) -> usize {
+    c.suit as usize * 13 + c.value as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_hand(env: &mut PokerEnv, seed: u64) -> Result<(Observation, f32), EngineError> {
+        let first = env.reset(seed)?;
+        let mut reward = 0.0;
+
+        while !env.is_done() {
+            let (_, r, _) = env.step(player_state::PlayerAction::Call)?;
+            reward += r;
+        }
+
+        Ok((first, reward))
+    }
+
+    #[test]
+    fn calling_agent() -> Result<(), EngineError> {
+        let mut env = PokerEnv::new(4, 2, 100, 1)?;
+
+        for seed in 0..20 {
+            let (obs, reward) = play_hand(&mut env, seed)?;
+
+            assert_eq!(obs.iter().take(52).filter(|&&x| x == 1.0).count(), 2);
+            assert!((-100.0..=300.0).contains(&reward));
+            assert!(env.step(player_state::PlayerAction::Call).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn seeded_cards() -> Result<(), EngineError> {
+        let mut env = PokerEnv::new(3, 0, 100, 1)?;
+
+        let a = env.reset(7)?;
+        let b = env.reset(7)?;
+        assert_eq!(a[..52], b[..52]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bad_environment() {
+        assert!(PokerEnv::new(1, 0, 100, 1).is_err());
+        assert!(PokerEnv::new(MAX_SEATS + 1, 0, 100, 1).is_err());
+        assert!(PokerEnv::new(4, 4, 100, 1).is_err());
+    }
+}
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-#[derive(Error, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum EngineError {
     #[error("Cannot take card from deck")]
     BadDeckError,
@@ -20,4 +21,10 @@ pub enum EngineError {
     BadGameError,
     #[error("The communication to my player couldn't be done")]
     RecvMyselfError,
+    #[error("The hand has ended, the environment must be reset")]
+    HandEndedError,
+    #[error("The player didn't answer in time")]
+    TimeoutError,
+    #[error("The connection with the remote player was lost")]
+    ConnectionError,
 }
@@ -1,6 +1,10 @@
+use std::cmp::Reverse;
+
+use itertools::Itertools;
+
 use crate::core::card::*;
 use crate::core::player::PlayerHand;
-use crate::core::rank;
+use crate::core::rank::{self, Rank, Rankable};
 
 use super::error::EngineError;
 
@@ -26,6 +30,25 @@ impl Hand {
     pub fn push(&mut self, card: Card) {
         self.cards.push(card);
     }
+
+    ///The five cards that make the rank of the hand, with the highest kickers
+    pub fn best_five(&self) -> Result<(Rank, Vec<Card>), EngineError> {
+        let cards = self.cards()?;
+
+        cards
+            .into_iter()
+            .combinations(5)
+            .map(|five| {
+                let rank = Hand::new_from_cards(&five).rank()?;
+                let kickers = five.iter().map(|c| Reverse(c.value)).sorted().collect_vec();
+                Ok((rank, kickers, five))
+            })
+            .collect::<Result<Vec<_>, EngineError>>()?
+            .into_iter()
+            .max_by(|a, b| (a.0, &b.1).cmp(&(b.0, &a.1)))
+            .map(|(rank, _, five)| (rank, five))
+            .ok_or(EngineError::SmallHandError)
+    }
 }
 
 impl rank::Rankable for Hand {
@@ -40,6 +63,8 @@ impl rank::Rankable for Hand {
 
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
+
     use crate::core::{card::*, hand::Hand, rank::*};
 
     #[test]
@@ -354,4 +379,26 @@ mod tests {
         let rank = hand.rank().unwrap();
         assert_eq!(rank, Rank::StraightFlush(Value::Ace, Suit::Diamond))
     }
+
+    #[test]
+    fn best_five_with_kickers() {
+        let hand = Hand::new_from_hand(
+            (Card::new(Suit::Club, Value::Ace), Card::new(Suit::Spade, Value::Two)),
+            &[
+                Card::new(Suit::Heart, Value::Ace),
+                Card::new(Suit::Heart, Value::King),
+                Card::new(Suit::Diamond, Value::Three),
+                Card::new(Suit::Club, Value::Jack),
+                Card::new(Suit::Spade, Value::Queen),
+            ],
+        );
+
+        let (rank, five) = hand.best_five().unwrap();
+        assert_eq!(rank, Rank::OnePair(Value::Ace));
+        assert_eq!(
+            five.iter().map(|c| c.value).sorted().collect::<Vec<_>>(),
+            vec![Value::Jack, Value::Queen, Value::King, Value::Ace, Value::Ace]
+        );
+        assert!(Hand::new_from_cards(&five[..4]).best_five().is_err());
+    }
 }
@@ -1,23 +1,76 @@
-use crate::core::{card::Card, error::EngineError, state::GameState};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    action::game_action::GameAction, card::Card, error::EngineError, state::GameState,
+    stats::SharedStats,
+};
+use crate::game::player_state;
 
 pub type PlayerHand = (Card, Card);
 
-pub trait Player: std::fmt::Debug {
+pub trait Player: std::fmt::Debug + Send {
     fn cards(&self) -> Option<PlayerHand>;
     fn give_cards(&mut self, hand: PlayerHand);
 
     fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError>;
 
     fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError>;
+
+    ///Gives access to the statistics of every seat in the session, ignored by default
+    fn track_stats(&mut self, _stats: SharedStats) {}
+
+    ///Called before the cards are dealt, with the seat of the player in this hand
+    fn start_hand(&mut self, _i: usize, _state: &GameState) {}
+
+    ///Called for every event of the hand this player is allowed to see
+    fn observe(&mut self, _action: &GameAction, _state: &GameState) {}
+
+    ///The decisions must be taken before the deadline, players that wait for someone can stop
+    ///waiting and return a timeout error. Ignored by default
+    fn set_deadline(&mut self, _deadline: Option<Instant>) {}
+
+    ///The player waits for someone outside the engine, like a person, so only its decisions are
+    ///timed. False by default
+    fn can_block(&self) -> bool {
+        false
+    }
+
+    ///Called once the pot has been awarded
+    fn end_hand(&mut self, _state: &GameState) {}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash, Serialize, Deserialize)]
 pub enum PlayerAction {
     Fold,
     Raise(i32),
     Call(i32),
 }
 
+impl PlayerAction {
+    ///Turns an action chosen outside the engine, where the raises are over the call, into the
+    ///amount bet. The blinds can't be raised
+    pub fn from_chosen(
+        chosen: player_state::PlayerAction,
+        state: &GameState,
+        i: usize,
+        can_raise: bool,
+    ) -> Result<Self, EngineError> {
+        let diff = state.bet_amount - state.players_bet[i]; //The amount to call
+
+        match chosen {
+            player_state::PlayerAction::Fold => Ok(PlayerAction::Fold),
+            player_state::PlayerAction::Call => Ok(PlayerAction::Call(diff)),
+            player_state::PlayerAction::Raise(raised) if can_raise => {
+                Ok(PlayerAction::Raise(diff + raised))
+            }
+            player_state::PlayerAction::Raise(_) => Err(EngineError::NoRaiseAllowedError),
+        }
+    }
+}
+
+pub mod agent;
 pub mod dummy;
 pub mod montecarlo;
 pub mod myself;
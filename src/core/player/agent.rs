@@ -0,0 +1,61 @@
+use std::sync::mpsc;
+
+use crate::{
+    core::{error::EngineError, state::GameState},
+    game::player_state,
+};
+
+use super::{Player, PlayerHand};
+
+///A player controlled from outside the engine thread, it sends every decision it has to make
+///and waits for the answer
+#[derive(Debug)]
+pub struct AgentPlayer {
+    hand: Option<PlayerHand>,
+    tx: mpsc::Sender<(GameState, usize)>,
+    rx: mpsc::Receiver<player_state::PlayerAction>,
+}
+
+impl Player for AgentPlayer {
+    fn play(&mut self, state: &GameState, i: usize) -> Result<super::PlayerAction, EngineError> {
+        let chosen = self.ask(state, i)?;
+        super::PlayerAction::from_chosen(chosen, state, i, true)
+    }
+
+    fn cards(&self) -> Option<PlayerHand> {
+        self.hand
+    }
+
+    fn give_cards(&mut self, hand: PlayerHand) {
+        self.hand = Some(hand);
+    }
+
+    fn can_block(&self) -> bool {
+        true
+    }
+
+    fn blind(&mut self, state: &GameState, i: usize) -> Result<super::PlayerAction, EngineError> {
+        let chosen = self.ask(state, i)?;
+        super::PlayerAction::from_chosen(chosen, state, i, false)
+    }
+}
+
+impl AgentPlayer {
+    pub fn new(
+        tx: mpsc::Sender<(GameState, usize)>,
+        rx: mpsc::Receiver<player_state::PlayerAction>,
+    ) -> Self {
+        AgentPlayer { hand: None, tx, rx }
+    }
+
+    fn ask(
+        &mut self,
+        state: &GameState,
+        i: usize,
+    ) -> Result<player_state::PlayerAction, EngineError> {
+        self.tx
+            .send((state.clone(), i))
+            .map_err(|_| EngineError::RecvMyselfError)?;
+        self.rx.recv().map_err(|_| EngineError::RecvMyselfError)
+    }
+}
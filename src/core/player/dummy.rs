@@ -1,6 +1,8 @@
 use rand::{thread_rng, Rng};
 
-use crate::core::{error::EngineError, hand::Hand, rank::Rankable, state::GameState};
+use crate::core::{
+    draw, error::EngineError, hand::Hand, preflop::PreflopEquity, rank::Rankable, state::GameState,
+};
 
 use super::*;
 
@@ -11,6 +13,10 @@ pub struct DummyPlayer {
 
 const FOLD_PROB: f64 = 0.2;
 const RAISE_PROB: f64 = 0.1;
+///Hands ranked up to one pair can be bluffed
+const WEAK_RANK: i32 = 2;
+///Starting hands with a lower equity than an even share of the pot multiplied by this are folded
+const BLIND_FOLD_EQUITY: f64 = 0.8;
 
 impl Player for DummyPlayer {
     fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
@@ -25,6 +31,17 @@ impl Player for DummyPlayer {
         let fold_prob = FOLD_PROB.powi(rank.to_i32());
         let raise_prob = RAISE_PROB * rank.to_i32() as f64;
 
+        //Weak hands bet sometimes with draws or scary boards
+        if rank.to_i32() <= WEAK_RANK {
+            let pot = state.players_bet.iter().sum();
+            let hand = self.hand.unwrap();
+            if let Some(raised) =
+                draw::bluff_raise(&mut rng, hand, &state.community, pot, diff, cash)
+            {
+                return Ok(PlayerAction::Raise(diff + raised));
+            }
+        }
+
         if rng.gen_bool(fold_prob) {
             Ok(PlayerAction::Fold)
         } else if rng.gen_bool(raise_prob) && cash > diff {
@@ -48,8 +65,10 @@ impl Player for DummyPlayer {
 
     fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
         let cash = state.players_money[i];
+        let n_players = state.players_money.len() - state.folded_players.len();
 
-        let fold = (self.hand.unwrap().0.value as i32 + self.hand.unwrap().1.value as i32) < 10;
+        let equity = self.hand.unwrap().preflop_equity(n_players - 1);
+        let fold = equity * (n_players as f64) < BLIND_FOLD_EQUITY;
 
         if fold || cash < state.bet_amount {
             Ok(PlayerAction::Fold)
@@ -1,19 +1,42 @@
 use std::ops::Div;
 
-use crate::core::{card::*, deck::*, error::EngineError, hand::*, rank::*, state::GameState};
+use crate::core::{
+    card::*, deck::*, draw, error::EngineError, hand::*, preflop::PreflopEquity, rank::*,
+    state::GameState, stats::SharedStats,
+};
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
 use rayon::prelude::*;
 
 use super::*;
 
-const SIM_ROUNDS: i32 = 5000;
+pub const SIM_ROUNDS: i32 = 5000;
 
 const BLIND_FOLD_PROB: f64 = 0.7;
 
-#[derive(Debug, Default)]
+///Opponents that play more hands than this are considered loose
+const LOOSE_VPIP: f64 = 0.6;
+///How much weaker a hand can be to keep playing against loose opponents
+const LOOSE_ADJUST: f64 = 0.15;
+///Minimum hands needed to trust the statistics of an opponent
+const MIN_STATS_HANDS: u32 = 10;
+
+#[derive(Debug)]
 pub struct MontecarloPlayer {
     hand: Option<PlayerHand>,
+    stats: Option<SharedStats>,
+    ///Games simulated before every decision
+    sim_rounds: i32,
+}
+
+impl Default for MontecarloPlayer {
+    fn default() -> Self {
+        MontecarloPlayer {
+            hand: None,
+            stats: None,
+            sim_rounds: SIM_ROUNDS,
+        }
+    }
 }
 
 impl Player for MontecarloPlayer {
@@ -29,7 +52,7 @@ impl Player for MontecarloPlayer {
         let cash = state.players_money[i];
         let n_players = state.players_money.len() - state.folded_players.len();
 
-        let (win, _lose) = self.montecarlo_sim(state, i, SIM_ROUNDS)?;
+        let win = self.hand.unwrap().preflop_equity(n_players - 1);
         let fold = (win * n_players as f64) < BLIND_FOLD_PROB;
 
         if fold || cash < state.bet_amount {
@@ -47,12 +70,18 @@ impl Player for MontecarloPlayer {
         let my_bet = state.players_bet[i];
         let diff = state.bet_amount - my_bet; //The amount to call
 
-        let (win, _lose) = self.montecarlo_sim(state, i, SIM_ROUNDS)?;
+        let (win, _lose) = self.montecarlo_sim(state, i, self.sim_rounds)?;
 
         let win_pp = win * n_players as f64;
 
-        if win_pp < 1.0 {
-            Ok(PlayerAction::Fold)
+        if win_pp < 1.0 - self.looseness(state, i) {
+            //Instead of giving up, bet sometimes with draws or scary boards
+            let pot = state.players_bet.iter().sum();
+            let hand = self.hand.unwrap();
+            match draw::bluff_raise(&mut rng, hand, &state.community, pot, diff, cash) {
+                Some(raised) => Ok(PlayerAction::Raise(diff + raised)),
+                None => Ok(PlayerAction::Fold),
+            }
         } else if rng.gen_bool((win_pp - 1.0).div(state.num_active_players as f64).min(1.0))
             && cash > diff
         {
@@ -65,9 +94,45 @@ impl Player for MontecarloPlayer {
             Ok(PlayerAction::Call(diff))
         }
     }
+
+    fn track_stats(&mut self, stats: SharedStats) {
+        self.stats = Some(stats);
+    }
 }
 
 impl MontecarloPlayer {
+    pub fn with_sim_rounds(mut self, sim_rounds: i32) -> Self {
+        self.sim_rounds = sim_rounds.max(1);
+        self
+    }
+
+    ///How much the fold threshold is lowered because the active opponents play too many hands
+    fn looseness(&self, state: &GameState, player_idx: usize) -> f64 {
+        let Some(stats) = self.stats.as_ref().and_then(|s| s.read().ok()) else {
+            return 0.0;
+        };
+
+        let vpips = state
+            .active_players
+            .iter()
+            .filter(|&&i| i != player_idx)
+            .map(|&i| stats.seat(i))
+            .filter(|s| s.hands >= MIN_STATS_HANDS)
+            .map(|s| s.vpip())
+            .collect_vec();
+
+        if vpips.is_empty() {
+            return 0.0;
+        }
+
+        let avg = vpips.iter().sum::<f64>() / vpips.len() as f64;
+        if avg > LOOSE_VPIP {
+            LOOSE_ADJUST
+        } else {
+            0.0
+        }
+    }
+
     fn play_montecarlo(&self, state: &GameState, player_idx: usize) -> Result<usize, EngineError> {
         let players_length = state.players_money.len();
 
@@ -204,6 +269,7 @@ mod tests {
                     value: Value::Ace,
                 },
             )),
+            ..Default::default()
         };
 
         for i in 0..7 {
@@ -259,6 +325,7 @@ mod tests {
                         value: Value::Ace,
                     },
                 )),
+                ..Default::default()
             };
 
             let p = m.play_montecarlo(&state, player_idx).unwrap();
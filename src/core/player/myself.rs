@@ -1,4 +1,5 @@
 use std::sync::mpsc;
+use std::time::Instant;
 
 use crate::{
     core::{error::EngineError, state::GameState},
@@ -11,20 +12,12 @@ use super::{Player, PlayerHand};
 pub struct MyselfPlayer {
     hand: Option<PlayerHand>,
     rx: mpsc::Receiver<player_state::PlayerAction>,
+    deadline: Option<Instant>,
 }
 
 impl Player for MyselfPlayer {
     fn play(&mut self, state: &GameState, i: usize) -> Result<super::PlayerAction, EngineError> {
-        let my_bet = state.players_bet[i];
-        let diff = state.bet_amount - my_bet; //The amount to call
-
-        let game_act = self.rx.recv().map_err(|_| EngineError::RecvMyselfError)?;
-
-        Ok(match game_act {
-            player_state::PlayerAction::Fold => super::PlayerAction::Fold,
-            player_state::PlayerAction::Call => super::PlayerAction::Call(diff),
-            player_state::PlayerAction::Raise(raised) => super::PlayerAction::Raise(diff + raised),
-        })
+        super::PlayerAction::from_chosen(self.receive()?, state, i, true)
     }
 
     fn cards(&self) -> Option<PlayerHand> {
@@ -35,22 +28,38 @@ impl Player for MyselfPlayer {
         self.hand = Some(hand);
     }
 
-    fn blind(&mut self, state: &GameState, i: usize) -> Result<super::PlayerAction, EngineError> {
-        let my_bet = state.players_bet[i];
-        let diff = state.bet_amount - my_bet; //The amount to call
+    fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
 
-        let game_act = self.rx.recv().map_err(|_| EngineError::RecvMyselfError)?;
+    fn can_block(&self) -> bool {
+        true
+    }
 
-        match game_act {
-            player_state::PlayerAction::Fold => Ok(super::PlayerAction::Fold),
-            player_state::PlayerAction::Call => Ok(super::PlayerAction::Call(diff)),
-            player_state::PlayerAction::Raise(_) => Err(EngineError::NoRaiseAllowedError),
-        }
+    fn blind(&mut self, state: &GameState, i: usize) -> Result<super::PlayerAction, EngineError> {
+        super::PlayerAction::from_chosen(self.receive()?, state, i, false)
     }
 }
 
 impl MyselfPlayer {
     pub fn new(rx: mpsc::Receiver<player_state::PlayerAction>) -> Self {
-        MyselfPlayer { hand: None, rx }
+        MyselfPlayer {
+            hand: None,
+            rx,
+            deadline: None,
+        }
+    }
+
+    fn receive(&self) -> Result<player_state::PlayerAction, EngineError> {
+        match self.deadline {
+            Some(deadline) => {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                self.rx.recv_timeout(timeout).map_err(|e| match e {
+                    mpsc::RecvTimeoutError::Timeout => EngineError::TimeoutError,
+                    mpsc::RecvTimeoutError::Disconnected => EngineError::RecvMyselfError,
+                })
+            }
+            None => self.rx.recv().map_err(|_| EngineError::RecvMyselfError),
+        }
     }
 }
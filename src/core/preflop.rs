@@ -0,0 +1,209 @@
+use std::fmt::Display;
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::core::{card::*, error::EngineError, hand::Hand, player::PlayerHand, rank::Rankable};
+
+mod table;
+
+///Number of different starting hands when the suits don't matter
+pub const HAND_CLASSES: usize = 169;
+///The table has the equity against 1 to this number of random opponents
+pub const MAX_OPPONENTS: usize = 9;
+
+///A starting hand without the suits, like "AKs", "72o" or "TT"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandClass {
+    pub high: Value,
+    pub low: Value,
+    pub suited: bool,
+}
+
+impl HandClass {
+    pub fn new(hand: PlayerHand) -> Self {
+        let (high, low) = if hand.0.value >= hand.1.value {
+            (hand.0.value, hand.1.value)
+        } else {
+            (hand.1.value, hand.0.value)
+        };
+
+        HandClass {
+            high,
+            low,
+            suited: hand.0.suit == hand.1.suit,
+        }
+    }
+
+    ///The position in a 13x13 grid, suited hands over the diagonal and offsuit hands under it
+    pub fn index(&self) -> usize {
+        let (h, l) = (self.high as usize, self.low as usize);
+        if self.suited {
+            h * 13 + l
+        } else {
+            l * 13 + h
+        }
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        let (a, b) = (index / 13, index % 13);
+        let value = |v: usize| VALUES[(v + 1) % 13]; //VALUES starts with the ace
+
+        HandClass {
+            high: value(a.max(b)),
+            low: value(a.min(b)),
+            suited: a > b,
+        }
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    ///Two cards of this class, the first ones of the deck
+    pub fn example(&self) -> PlayerHand {
+        let second_suit = if self.suited {
+            Suit::Club
+        } else {
+            Suit::Diamond
+        };
+        (
+            Card::new(Suit::Club, self.high),
+            Card::new(second_suit, self.low),
+        )
+    }
+}
+
+impl Display for HandClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.high.symbol(), self.low.symbol())?;
+        if !self.is_pair() {
+            write!(f, "{}", if self.suited { 's' } else { 'o' })?;
+        }
+
+        Ok(())
+    }
+}
+
+///Instant strength of a starting hand, from a precomputed table
+pub trait PreflopEquity {
+    ///The share of the pot won on average against the number of random opponents
+    fn preflop_equity(&self, opponents: usize) -> f64;
+}
+
+impl PreflopEquity for PlayerHand {
+    fn preflop_equity(&self, opponents: usize) -> f64 {
+        let opponents = opponents.clamp(1, MAX_OPPONENTS);
+        table::PREFLOP_EQUITY[HandClass::new(*self).index()][opponents - 1] as f64
+    }
+}
+
+///Simulates hands of the class against random opponents, dividing the pot when there are ties
+///<br>The same seed always returns the same equity
+pub fn simulate_equity(
+    class: HandClass,
+    opponents: usize,
+    rounds: u32,
+    seed: u64,
+) -> Result<f64, EngineError> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let hand = class.example();
+
+    let mut deck = Vec::new();
+    for suit in SUITS {
+        for value in VALUES {
+            let c = Card::new(suit, value);
+            if c != hand.0 && c != hand.1 {
+                deck.push(c);
+            }
+        }
+    }
+
+    let mut equity = 0.0;
+    for _ in 0..rounds {
+        let (cards, _) = deck.partial_shuffle(&mut rng, 5 + 2 * opponents);
+        let (community, others) = cards.split_at(5);
+
+        let mine = Hand::new_from_hand(hand, community).rank()?;
+        let mut best = mine;
+        let mut ties = 1;
+        for h in others.chunks(2) {
+            let rank = Hand::new_from_hand((h[0], h[1]), community).rank()?;
+            //Ranks are compared as in the engine, the suit of a flush doesn't matter
+            if rank == best {
+                ties += 1;
+            } else if rank > best {
+                best = rank;
+                ties = 1;
+            }
+        }
+
+        if mine == best {
+            equity += 1.0 / ties as f64;
+        }
+    }
+
+    Ok(equity / rounds as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn class_index() {
+        let mut classes = HashSet::new();
+        for i in 0..HAND_CLASSES {
+            let class = HandClass::from_index(i);
+            assert_eq!(class.index(), i);
+            assert_eq!(HandClass::new(class.example()), class);
+            classes.insert(class.to_string());
+        }
+        assert_eq!(classes.len(), HAND_CLASSES);
+    }
+
+    #[test]
+    fn class_names() {
+        let ace = Card::new(Suit::Spade, Value::Ace);
+        let king = Card::new(Suit::Spade, Value::King);
+        let two = Card::new(Suit::Heart, Value::Two);
+        let seven = Card::new(Suit::Club, Value::Seven);
+
+        assert_eq!(HandClass::new((king, ace)).to_string(), "AKs");
+        assert_eq!(HandClass::new((two, seven)).to_string(), "72o");
+        assert_eq!(
+            HandClass::new((ace, Card::new(Suit::Club, Value::Ace))).to_string(),
+            "AA"
+        );
+    }
+
+    #[test]
+    fn table_order() {
+        let aces = (
+            Card::new(Suit::Spade, Value::Ace),
+            Card::new(Suit::Heart, Value::Ace),
+        );
+        let trash = (
+            Card::new(Suit::Spade, Value::Seven),
+            Card::new(Suit::Heart, Value::Two),
+        );
+
+        for opponents in 1..=MAX_OPPONENTS {
+            assert!(aces.preflop_equity(opponents) > trash.preflop_equity(opponents));
+            assert!(aces.preflop_equity(opponents) > aces.preflop_equity(opponents + 1) - 1e-6);
+        }
+        assert!(aces.preflop_equity(1) > 0.8);
+    }
+
+    #[test]
+    fn reproducible_simulation() -> Result<(), EngineError> {
+        let class = HandClass::from_index(0);
+        assert_eq!(
+            simulate_equity(class, 3, 200, 1)?,
+            simulate_equity(class, 3, 200, 1)?
+        );
+
+        Ok(())
+    }
+}
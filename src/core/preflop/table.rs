@@ -0,0 +1,176 @@
+//Generated by examples/preflop_table.rs with 20000 rounds for every hand, don't edit
+#![allow(clippy::approx_constant)]
+
+///Equity of every hand class against 1 to 9 random opponents
+#[rustfmt::skip]
+pub const PREFLOP_EQUITY: [[f32; 9]; 169] = [
+    [0.5172, 0.3126, 0.2215, 0.1831, 0.1645, 0.1527, 0.1406, 0.1307, 0.1158], //22
+    [0.4080, 0.2354, 0.1601, 0.1266, 0.0998, 0.0870, 0.0743, 0.0681, 0.0616], //32o
+    [0.4192, 0.2423, 0.1665, 0.1262, 0.1058, 0.0919, 0.0811, 0.0721, 0.0679], //42o
+    [0.4258, 0.2511, 0.1747, 0.1276, 0.1119, 0.0944, 0.0858, 0.0762, 0.0700], //52o
+    [0.4127, 0.2405, 0.1625, 0.1231, 0.1001, 0.0806, 0.0760, 0.0652, 0.0607], //62o
+    [0.4165, 0.2349, 0.1610, 0.1223, 0.0947, 0.0796, 0.0671, 0.0600, 0.0535], //72o
+    [0.4257, 0.2545, 0.1688, 0.1255, 0.1009, 0.0847, 0.0719, 0.0597, 0.0568], //82o
+    [0.4311, 0.2577, 0.1748, 0.1310, 0.1059, 0.0854, 0.0726, 0.0613, 0.0587], //92o
+    [0.4428, 0.2676, 0.1911, 0.1439, 0.1144, 0.0988, 0.0821, 0.0720, 0.0625], //T2o
+    [0.4417, 0.2736, 0.1955, 0.1497, 0.1216, 0.0990, 0.0858, 0.0763, 0.0661], //J2o
+    [0.4494, 0.2802, 0.2074, 0.1633, 0.1321, 0.1060, 0.0933, 0.0858, 0.0702], //Q2o
+    [0.4554, 0.2946, 0.2169, 0.1652, 0.1400, 0.1173, 0.1052, 0.0934, 0.0783], //K2o
+    [0.4954, 0.3198, 0.2421, 0.1951, 0.1597, 0.1378, 0.1197, 0.1037, 0.0930], //A2o
+    [0.4364, 0.2749, 0.2038, 0.1660, 0.1399, 0.1251, 0.1168, 0.1072, 0.1038], //32s
+    [0.5512, 0.3453, 0.2434, 0.1971, 0.1685, 0.1536, 0.1395, 0.1345, 0.1253], //33
+    [0.4385, 0.2586, 0.1819, 0.1429, 0.1131, 0.1018, 0.0903, 0.0843, 0.0763], //43o
+    [0.4426, 0.2725, 0.1838, 0.1491, 0.1225, 0.1081, 0.1000, 0.0875, 0.0758], //53o
+    [0.4359, 0.2645, 0.1858, 0.1360, 0.1148, 0.0989, 0.0869, 0.0762, 0.0709], //63o
+    [0.4337, 0.2618, 0.1784, 0.1342, 0.1132, 0.0919, 0.0797, 0.0707, 0.0625], //73o
+    [0.4335, 0.2588, 0.1711, 0.1315, 0.1074, 0.0864, 0.0719, 0.0635, 0.0561], //83o
+    [0.4448, 0.2666, 0.1800, 0.1362, 0.1105, 0.0897, 0.0741, 0.0658, 0.0594], //93o
+    [0.4417, 0.2759, 0.1882, 0.1461, 0.1185, 0.0967, 0.0816, 0.0696, 0.0646], //T3o
+    [0.4565, 0.2775, 0.2039, 0.1521, 0.1272, 0.1077, 0.0893, 0.0789, 0.0675], //J3o
+    [0.4545, 0.2870, 0.2108, 0.1631, 0.1366, 0.1125, 0.0970, 0.0853, 0.0721], //Q3o
+    [0.4697, 0.3030, 0.2200, 0.1801, 0.1439, 0.1201, 0.1038, 0.0937, 0.0797], //K3o
+    [0.5006, 0.3272, 0.2473, 0.1957, 0.1664, 0.1452, 0.1238, 0.1076, 0.0951], //A3o
+    [0.4433, 0.2812, 0.2074, 0.1673, 0.1429, 0.1292, 0.1176, 0.1123, 0.1032], //42s
+    [0.4685, 0.3004, 0.2219, 0.1820, 0.1578, 0.1443, 0.1360, 0.1194, 0.1170], //43s
+    [0.5807, 0.3720, 0.2695, 0.2114, 0.1728, 0.1541, 0.1383, 0.1343, 0.1257], //44
+    [0.4557, 0.2868, 0.2077, 0.1612, 0.1315, 0.1165, 0.1063, 0.0937, 0.0886], //54o
+    [0.4550, 0.2834, 0.1987, 0.1556, 0.1264, 0.1079, 0.0967, 0.0833, 0.0824], //64o
+    [0.4509, 0.2797, 0.1973, 0.1545, 0.1253, 0.1025, 0.0881, 0.0818, 0.0729], //74o
+    [0.4418, 0.2754, 0.1905, 0.1471, 0.1198, 0.1007, 0.0839, 0.0765, 0.0670], //84o
+    [0.4403, 0.2734, 0.1827, 0.1410, 0.1133, 0.0936, 0.0780, 0.0691, 0.0625], //94o
+    [0.4525, 0.2814, 0.2018, 0.1567, 0.1226, 0.1024, 0.0830, 0.0746, 0.0656], //T4o
+    [0.4572, 0.2960, 0.2082, 0.1581, 0.1270, 0.1108, 0.0934, 0.0814, 0.0711], //J4o
+    [0.4622, 0.3010, 0.2168, 0.1696, 0.1411, 0.1171, 0.0977, 0.0882, 0.0764], //Q4o
+    [0.4788, 0.3099, 0.2279, 0.1793, 0.1516, 0.1250, 0.1096, 0.0939, 0.0847], //K4o
+    [0.5142, 0.3365, 0.2625, 0.2012, 0.1691, 0.1429, 0.1305, 0.1139, 0.0973], //A4o
+    [0.4521, 0.2900, 0.2088, 0.1703, 0.1478, 0.1380, 0.1222, 0.1109, 0.1103], //52s
+    [0.4734, 0.3045, 0.2289, 0.1836, 0.1657, 0.1456, 0.1334, 0.1219, 0.1182], //53s
+    [0.4870, 0.3241, 0.2461, 0.2046, 0.1741, 0.1589, 0.1415, 0.1295, 0.1275], //54s
+    [0.6102, 0.4042, 0.2899, 0.2290, 0.1944, 0.1707, 0.1504, 0.1334, 0.1257], //55
+    [0.4733, 0.2955, 0.2192, 0.1704, 0.1413, 0.1222, 0.1119, 0.0965, 0.0899], //65o
+    [0.4665, 0.2958, 0.2131, 0.1646, 0.1356, 0.1194, 0.1004, 0.0946, 0.0867], //75o
+    [0.4637, 0.2887, 0.2084, 0.1636, 0.1306, 0.1123, 0.1001, 0.0864, 0.0764], //85o
+    [0.4632, 0.2864, 0.2024, 0.1567, 0.1288, 0.1063, 0.0882, 0.0810, 0.0707], //95o
+    [0.4610, 0.2900, 0.2013, 0.1610, 0.1252, 0.1046, 0.0892, 0.0749, 0.0698], //T5o
+    [0.4718, 0.2967, 0.2168, 0.1671, 0.1346, 0.1096, 0.0964, 0.0817, 0.0741], //J5o
+    [0.4776, 0.3071, 0.2210, 0.1758, 0.1443, 0.1208, 0.1021, 0.0887, 0.0742], //Q5o
+    [0.4915, 0.3212, 0.2414, 0.1824, 0.1503, 0.1290, 0.1106, 0.0936, 0.0843], //K5o
+    [0.5248, 0.3458, 0.2638, 0.2136, 0.1719, 0.1491, 0.1308, 0.1180, 0.1016], //A5o
+    [0.4452, 0.2768, 0.2081, 0.1649, 0.1381, 0.1209, 0.1111, 0.0979, 0.1002], //62s
+    [0.4659, 0.3033, 0.2175, 0.1790, 0.1545, 0.1380, 0.1264, 0.1189, 0.1085], //63s
+    [0.4891, 0.3160, 0.2379, 0.1899, 0.1676, 0.1463, 0.1394, 0.1231, 0.1217], //64s
+    [0.5023, 0.3374, 0.2550, 0.2070, 0.1809, 0.1585, 0.1425, 0.1397, 0.1277], //65s
+    [0.6375, 0.4376, 0.3248, 0.2429, 0.2054, 0.1707, 0.1541, 0.1497, 0.1352], //66
+    [0.4863, 0.3127, 0.2268, 0.1792, 0.1496, 0.1259, 0.1165, 0.1047, 0.0936], //76o
+    [0.4845, 0.3126, 0.2314, 0.1768, 0.1470, 0.1232, 0.1072, 0.0944, 0.0876], //86o
+    [0.4814, 0.3071, 0.2229, 0.1700, 0.1440, 0.1180, 0.1052, 0.0923, 0.0788], //96o
+    [0.4712, 0.3102, 0.2240, 0.1672, 0.1410, 0.1157, 0.1017, 0.0871, 0.0762], //T6o
+    [0.4724, 0.3037, 0.2155, 0.1713, 0.1346, 0.1161, 0.0956, 0.0833, 0.0730], //J6o
+    [0.4780, 0.3121, 0.2307, 0.1792, 0.1452, 0.1220, 0.1031, 0.0865, 0.0784], //Q6o
+    [0.4951, 0.3216, 0.2460, 0.1931, 0.1580, 0.1346, 0.1147, 0.0975, 0.0858], //K6o
+    [0.5216, 0.3414, 0.2518, 0.2004, 0.1702, 0.1388, 0.1204, 0.1058, 0.0933], //A6o
+    [0.4483, 0.2804, 0.2057, 0.1592, 0.1372, 0.1229, 0.1153, 0.1001, 0.0933], //72s
+    [0.4647, 0.2955, 0.2205, 0.1788, 0.1556, 0.1328, 0.1204, 0.1090, 0.1016], //73s
+    [0.4829, 0.3160, 0.2286, 0.1925, 0.1625, 0.1444, 0.1281, 0.1205, 0.1127], //74s
+    [0.4968, 0.3374, 0.2509, 0.2016, 0.1766, 0.1582, 0.1477, 0.1311, 0.1220], //75s
+    [0.5126, 0.3553, 0.2667, 0.2220, 0.1852, 0.1664, 0.1538, 0.1450, 0.1287], //76s
+    [0.6640, 0.4711, 0.3482, 0.2686, 0.2244, 0.1873, 0.1670, 0.1490, 0.1420], //77
+    [0.4959, 0.3216, 0.2424, 0.1940, 0.1581, 0.1377, 0.1168, 0.1060, 0.0949], //87o
+    [0.4921, 0.3315, 0.2424, 0.1896, 0.1565, 0.1339, 0.1161, 0.1018, 0.0940], //97o
+    [0.4954, 0.3231, 0.2403, 0.1904, 0.1591, 0.1303, 0.1141, 0.0961, 0.0890], //T7o
+    [0.4881, 0.3169, 0.2391, 0.1842, 0.1500, 0.1258, 0.1066, 0.0921, 0.0833], //J7o
+    [0.4863, 0.3196, 0.2341, 0.1845, 0.1486, 0.1247, 0.1088, 0.0906, 0.0792], //Q7o
+    [0.4976, 0.3320, 0.2499, 0.1931, 0.1639, 0.1356, 0.1169, 0.1021, 0.0885], //K7o
+    [0.5261, 0.3527, 0.2614, 0.2068, 0.1745, 0.1476, 0.1264, 0.1090, 0.0967], //A7o
+    [0.4581, 0.2891, 0.2077, 0.1666, 0.1453, 0.1253, 0.1123, 0.1039, 0.0920], //82s
+    [0.4587, 0.2892, 0.2193, 0.1695, 0.1499, 0.1322, 0.1181, 0.0998, 0.0981], //83s
+    [0.4791, 0.3098, 0.2332, 0.1866, 0.1573, 0.1407, 0.1294, 0.1089, 0.1022], //84s
+    [0.4919, 0.3306, 0.2463, 0.2003, 0.1720, 0.1519, 0.1308, 0.1257, 0.1177], //85s
+    [0.5057, 0.3495, 0.2650, 0.2175, 0.1794, 0.1630, 0.1504, 0.1312, 0.1282], //86s
+    [0.5208, 0.3651, 0.2756, 0.2289, 0.1974, 0.1748, 0.1596, 0.1459, 0.1335], //87s
+    [0.6970, 0.4991, 0.3800, 0.2928, 0.2372, 0.2028, 0.1762, 0.1593, 0.1474], //88
+    [0.5026, 0.3474, 0.2584, 0.2087, 0.1680, 0.1441, 0.1253, 0.1105, 0.0992], //98o
+    [0.5083, 0.3447, 0.2620, 0.2044, 0.1694, 0.1476, 0.1288, 0.1115, 0.1007], //T8o
+    [0.5009, 0.3397, 0.2520, 0.1991, 0.1640, 0.1397, 0.1227, 0.1054, 0.0934], //J8o
+    [0.4971, 0.3435, 0.2542, 0.1990, 0.1630, 0.1393, 0.1178, 0.1035, 0.0897], //Q8o
+    [0.5041, 0.3380, 0.2545, 0.2036, 0.1605, 0.1364, 0.1201, 0.1007, 0.0911], //K8o
+    [0.5333, 0.3566, 0.2715, 0.2160, 0.1795, 0.1449, 0.1236, 0.1122, 0.0963], //A8o
+    [0.4633, 0.2975, 0.2170, 0.1732, 0.1490, 0.1286, 0.1158, 0.1020, 0.0968], //92s
+    [0.4683, 0.3002, 0.2223, 0.1795, 0.1522, 0.1286, 0.1206, 0.1067, 0.0970], //93s
+    [0.4741, 0.3072, 0.2269, 0.1804, 0.1579, 0.1328, 0.1176, 0.1052, 0.0985], //94s
+    [0.4864, 0.3255, 0.2424, 0.1962, 0.1657, 0.1484, 0.1348, 0.1196, 0.1095], //95s
+    [0.5050, 0.3452, 0.2642, 0.2128, 0.1803, 0.1598, 0.1417, 0.1264, 0.1214], //96s
+    [0.5199, 0.3594, 0.2760, 0.2294, 0.1971, 0.1742, 0.1522, 0.1413, 0.1261], //97s
+    [0.5360, 0.3735, 0.2955, 0.2405, 0.2041, 0.1801, 0.1647, 0.1499, 0.1345], //98s
+    [0.7195, 0.5342, 0.4091, 0.3270, 0.2607, 0.2262, 0.1958, 0.1707, 0.1569], //99
+    [0.5176, 0.3696, 0.2820, 0.2225, 0.1858, 0.1643, 0.1452, 0.1250, 0.1164], //T9o
+    [0.5262, 0.3633, 0.2750, 0.2218, 0.1847, 0.1602, 0.1396, 0.1158, 0.1098], //J9o
+    [0.5178, 0.3472, 0.2720, 0.2138, 0.1827, 0.1510, 0.1318, 0.1144, 0.1020], //Q9o
+    [0.5137, 0.3565, 0.2696, 0.2184, 0.1852, 0.1555, 0.1317, 0.1171, 0.0997], //K9o
+    [0.5384, 0.3619, 0.2722, 0.2191, 0.1839, 0.1513, 0.1322, 0.1127, 0.0980], //A9o
+    [0.4669, 0.3073, 0.2265, 0.1815, 0.1521, 0.1374, 0.1231, 0.1138, 0.1039], //T2s
+    [0.4719, 0.3054, 0.2347, 0.1886, 0.1561, 0.1430, 0.1237, 0.1122, 0.1029], //T3s
+    [0.4763, 0.3173, 0.2469, 0.1895, 0.1678, 0.1399, 0.1278, 0.1122, 0.1019], //T4s
+    [0.4898, 0.3286, 0.2440, 0.1983, 0.1643, 0.1471, 0.1283, 0.1198, 0.1069], //T5s
+    [0.5035, 0.3459, 0.2612, 0.2114, 0.1779, 0.1609, 0.1371, 0.1250, 0.1150], //T6s
+    [0.5141, 0.3602, 0.2735, 0.2264, 0.1930, 0.1690, 0.1498, 0.1377, 0.1293], //T7s
+    [0.5325, 0.3761, 0.2962, 0.2432, 0.2100, 0.1827, 0.1661, 0.1509, 0.1413], //T8s
+    [0.5467, 0.3940, 0.3096, 0.2607, 0.2283, 0.1975, 0.1810, 0.1621, 0.1488], //T9s
+    [0.7488, 0.5798, 0.4523, 0.3587, 0.3054, 0.2476, 0.2136, 0.1941, 0.1732], //TT
+    [0.5417, 0.3917, 0.3039, 0.2439, 0.2064, 0.1719, 0.1600, 0.1420, 0.1337], //JTo
+    [0.5297, 0.3842, 0.2914, 0.2433, 0.2092, 0.1797, 0.1541, 0.1398, 0.1234], //QTo
+    [0.5424, 0.3780, 0.2930, 0.2467, 0.2045, 0.1771, 0.1508, 0.1346, 0.1181], //KTo
+    [0.5574, 0.3888, 0.2963, 0.2432, 0.2077, 0.1769, 0.1521, 0.1348, 0.1170], //ATo
+    [0.4697, 0.3104, 0.2298, 0.1955, 0.1633, 0.1426, 0.1257, 0.1162, 0.1078], //J2s
+    [0.4755, 0.3163, 0.2422, 0.1975, 0.1674, 0.1434, 0.1276, 0.1185, 0.1084], //J3s
+    [0.4872, 0.3250, 0.2442, 0.1981, 0.1701, 0.1482, 0.1331, 0.1183, 0.1096], //J4s
+    [0.4973, 0.3344, 0.2553, 0.2015, 0.1706, 0.1560, 0.1373, 0.1187, 0.1064], //J5s
+    [0.4945, 0.3391, 0.2585, 0.2029, 0.1767, 0.1495, 0.1400, 0.1211, 0.1089], //J6s
+    [0.5140, 0.3554, 0.2688, 0.2221, 0.1885, 0.1656, 0.1515, 0.1354, 0.1271], //J7s
+    [0.5298, 0.3709, 0.2940, 0.2397, 0.2090, 0.1793, 0.1583, 0.1439, 0.1322], //J8s
+    [0.5417, 0.3909, 0.3114, 0.2563, 0.2288, 0.1935, 0.1708, 0.1625, 0.1457], //J9s
+    [0.5600, 0.4203, 0.3371, 0.2827, 0.2427, 0.2144, 0.1935, 0.1760, 0.1637], //JTs
+    [0.7710, 0.6078, 0.4886, 0.3960, 0.3367, 0.2850, 0.2462, 0.2079, 0.1933], //JJ
+    [0.5279, 0.3873, 0.3072, 0.2556, 0.2129, 0.1845, 0.1589, 0.1405, 0.1258], //QJo
+    [0.5369, 0.3823, 0.3054, 0.2514, 0.2162, 0.1848, 0.1572, 0.1344, 0.1198], //KJo
+    [0.5578, 0.3908, 0.3028, 0.2537, 0.2088, 0.1794, 0.1616, 0.1353, 0.1216], //AJo
+    [0.4799, 0.3176, 0.2439, 0.1971, 0.1659, 0.1451, 0.1338, 0.1161, 0.1088], //Q2s
+    [0.4832, 0.3256, 0.2499, 0.2015, 0.1694, 0.1494, 0.1372, 0.1229, 0.1076], //Q3s
+    [0.4973, 0.3380, 0.2515, 0.2129, 0.1759, 0.1589, 0.1399, 0.1265, 0.1133], //Q4s
+    [0.5072, 0.3391, 0.2659, 0.2124, 0.1827, 0.1579, 0.1406, 0.1248, 0.1167], //Q5s
+    [0.5079, 0.3413, 0.2691, 0.2161, 0.1808, 0.1580, 0.1462, 0.1284, 0.1139], //Q6s
+    [0.5142, 0.3503, 0.2725, 0.2208, 0.1899, 0.1651, 0.1463, 0.1294, 0.1178], //Q7s
+    [0.5250, 0.3714, 0.2870, 0.2405, 0.2041, 0.1781, 0.1528, 0.1438, 0.1307], //Q8s
+    [0.5434, 0.3913, 0.3102, 0.2527, 0.2202, 0.1872, 0.1733, 0.1477, 0.1335], //Q9s
+    [0.5607, 0.4184, 0.3358, 0.2827, 0.2406, 0.2132, 0.1908, 0.1677, 0.1552], //QTs
+    [0.5633, 0.4165, 0.3383, 0.2877, 0.2508, 0.2225, 0.2010, 0.1808, 0.1585], //QJs
+    [0.7925, 0.6424, 0.5328, 0.4447, 0.3716, 0.3175, 0.2721, 0.2423, 0.2200], //QQ
+    [0.5398, 0.3951, 0.3153, 0.2606, 0.2245, 0.1900, 0.1676, 0.1471, 0.1313], //KQo
+    [0.5593, 0.4025, 0.3155, 0.2586, 0.2222, 0.1872, 0.1641, 0.1437, 0.1257], //AQo
+    [0.4875, 0.3275, 0.2591, 0.2098, 0.1838, 0.1585, 0.1409, 0.1285, 0.1158], //K2s
+    [0.4980, 0.3336, 0.2651, 0.2149, 0.1821, 0.1605, 0.1427, 0.1284, 0.1191], //K3s
+    [0.5112, 0.3377, 0.2661, 0.2216, 0.1858, 0.1690, 0.1461, 0.1353, 0.1214], //K4s
+    [0.5188, 0.3541, 0.2778, 0.2254, 0.1909, 0.1678, 0.1526, 0.1378, 0.1225], //K5s
+    [0.5187, 0.3591, 0.2808, 0.2300, 0.2017, 0.1736, 0.1532, 0.1364, 0.1209], //K6s
+    [0.5285, 0.3671, 0.2828, 0.2307, 0.2005, 0.1759, 0.1518, 0.1393, 0.1277], //K7s
+    [0.5338, 0.3753, 0.2885, 0.2396, 0.2071, 0.1815, 0.1596, 0.1414, 0.1261], //K8s
+    [0.5438, 0.3917, 0.3082, 0.2560, 0.2238, 0.1914, 0.1679, 0.1469, 0.1362], //K9s
+    [0.5588, 0.4095, 0.3333, 0.2805, 0.2421, 0.2124, 0.1887, 0.1722, 0.1566], //KTs
+    [0.5645, 0.4186, 0.3441, 0.2910, 0.2481, 0.2280, 0.1952, 0.1782, 0.1597], //KJs
+    [0.5651, 0.4272, 0.3432, 0.2977, 0.2597, 0.2317, 0.2016, 0.1806, 0.1640], //KQs
+    [0.8214, 0.6861, 0.5797, 0.4921, 0.4247, 0.3693, 0.3252, 0.2841, 0.2565], //KK
+    [0.5658, 0.4069, 0.3286, 0.2764, 0.2368, 0.2029, 0.1744, 0.1545, 0.1335], //AKo
+    [0.5246, 0.3582, 0.2738, 0.2362, 0.2006, 0.1769, 0.1611, 0.1372, 0.1298], //A2s
+    [0.5279, 0.3673, 0.2797, 0.2364, 0.2059, 0.1798, 0.1624, 0.1481, 0.1363], //A3s
+    [0.5381, 0.3796, 0.2935, 0.2430, 0.2102, 0.1825, 0.1657, 0.1527, 0.1360], //A4s
+    [0.5430, 0.3809, 0.3025, 0.2486, 0.2102, 0.1888, 0.1687, 0.1536, 0.1398], //A5s
+    [0.5453, 0.3731, 0.2857, 0.2352, 0.2093, 0.1821, 0.1574, 0.1435, 0.1311], //A6s
+    [0.5603, 0.3878, 0.3007, 0.2467, 0.2124, 0.1881, 0.1645, 0.1450, 0.1332], //A7s
+    [0.5562, 0.3952, 0.3084, 0.2561, 0.2156, 0.1868, 0.1653, 0.1478, 0.1368], //A8s
+    [0.5599, 0.4003, 0.3142, 0.2606, 0.2189, 0.1896, 0.1667, 0.1523, 0.1366], //A9s
+    [0.5776, 0.4202, 0.3309, 0.2799, 0.2361, 0.2188, 0.1861, 0.1713, 0.1534], //ATs
+    [0.5781, 0.4263, 0.3464, 0.2889, 0.2577, 0.2194, 0.1984, 0.1724, 0.1625], //AJs
+    [0.5867, 0.4360, 0.3537, 0.2982, 0.2595, 0.2269, 0.1996, 0.1761, 0.1664], //AQs
+    [0.5940, 0.4434, 0.3655, 0.3102, 0.2733, 0.2357, 0.2141, 0.1874, 0.1681], //AKs
+    [0.8464, 0.7288, 0.6289, 0.5560, 0.4810, 0.4241, 0.3736, 0.3362, 0.2907], //AA
+];
@@ -1,12 +1,13 @@
 use std::cmp::Reverse;
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
 use crate::core::card::*;
 
 use super::error::EngineError;
 
-#[derive(PartialOrd, Ord, Eq, Debug, Clone, Copy)]
+#[derive(PartialOrd, Ord, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Rank {
     ///The highest card in the hand
     HighCard(Value),
@@ -46,6 +47,21 @@ impl Rank {
             Rank::StraightFlush(_, _) => 9,
         }
     }
+
+    ///The category of the hand in words, like "two pair"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::HighCard(_) => "high card",
+            Rank::OnePair(_) => "a pair",
+            Rank::TwoPair(_, _) => "two pair",
+            Rank::ThreeOfAKind(_) => "three of a kind",
+            Rank::Straight(_) => "a straight",
+            Rank::Flush(_, _) => "a flush",
+            Rank::FullHouse(_, _) => "a full house",
+            Rank::FourOfAKind(_) => "four of a kind",
+            Rank::StraightFlush(_, _) => "a straight flush",
+        }
+    }
 }
 
 impl PartialEq for Rank {
@@ -0,0 +1,167 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{card::Card, player::PlayerHand, state::GameState};
+
+///The last snapshot of the hand being played, shared between the engine and who saves it
+pub type SharedSnapshot = Arc<Mutex<Option<Snapshot>>>;
+
+///Everything needed to continue a hand from a decision, with the cards that weren't dealt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    ///The bets, stacks and community cards
+    pub state: GameState,
+    ///The cards left in the deck, the last one is dealt first
+    pub deck: Vec<Card>,
+    pub hands: Vec<PlayerHand>,
+    ///The seats left to play in this pass of the betting round, the first one is deciding
+    pub pending: Vec<usize>,
+    ///Someone raised before in this pass, so there will be another one
+    pub raising: bool,
+}
+
+impl Snapshot {
+    ///The seat deciding when the snapshot was taken
+    pub fn turn(&self) -> Option<usize> {
+        self.pending.first().copied()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        action::test_queue::EmptyQueue,
+        card::{Suit, Value},
+        engine::Engine,
+        error::EngineError,
+        player::{Player, PlayerAction},
+        state::Round,
+    };
+
+    use super::*;
+
+    ///Calls everything and keeps the snapshots taken before its decisions
+    #[derive(Debug, Default)]
+    struct CallingPlayer {
+        hand: Option<PlayerHand>,
+        slot: Option<SharedSnapshot>,
+        seen: Arc<Mutex<Vec<Snapshot>>>,
+    }
+
+    impl CallingPlayer {
+        fn call(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            if let Some(slot) = &self.slot {
+                if let Some(snapshot) = slot.lock().unwrap().clone() {
+                    self.seen.lock().unwrap().push(snapshot);
+                }
+            }
+
+            Ok(PlayerAction::Call(state.bet_amount - state.players_bet[i]))
+        }
+    }
+
+    impl Player for CallingPlayer {
+        fn cards(&self) -> Option<PlayerHand> {
+            self.hand
+        }
+
+        fn give_cards(&mut self, hand: PlayerHand) {
+            self.hand = Some(hand);
+        }
+
+        fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            self.call(state, i)
+        }
+
+        fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+            self.call(state, i)
+        }
+    }
+
+    fn callers(n: usize) -> Vec<Box<dyn Player>> {
+        (0..n)
+            .map(|_| Box::<CallingPlayer>::default() as Box<dyn Player>)
+            .collect()
+    }
+
+    #[test]
+    fn resume_hand() -> Result<(), EngineError> {
+        let slot = SharedSnapshot::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let mut players = callers(4);
+        players[2] = Box::new(CallingPlayer {
+            hand: None,
+            slot: Some(slot.clone()),
+            seen: seen.clone(),
+        });
+        let stacks = Engine::new(players, Box::<EmptyQueue>::default())?
+            .with_seed(3)
+            .with_snapshots(slot.clone())
+            .run(vec![100; 4], 1)?;
+        assert!(slot.lock().unwrap().is_none());
+
+        //Every snapshot taken in the hand ends the same way
+        let seen = seen.lock().unwrap().clone();
+        assert!(seen.iter().any(|s| s.state.round == Round::Turn));
+        for snapshot in seen {
+            assert_eq!(snapshot.turn(), Some(2));
+
+            let resumed = Engine::new(callers(4), Box::<EmptyQueue>::default())?
+                .resume_from(snapshot)?
+                .run_resumed()?;
+            assert_eq!(resumed, stacks);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn save_and_load() -> io::Result<()> {
+        let snapshot = Snapshot {
+            state: GameState {
+                round: Round::Flop,
+                community: vec![],
+                players_bet: vec![1; 3],
+                players_money: vec![49; 3],
+                bet_amount: 1,
+                players_all_in: vec![],
+                folded_players: vec![],
+                num_active_players: 3,
+                active_players: vec![0, 1, 2],
+            },
+            deck: vec![Card::new(Suit::Club, Value::Ace)],
+            hands: vec![],
+            pending: vec![1, 2],
+            raising: true,
+        };
+
+        let name = format!("poker-snapshot-{}.json", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        snapshot.save(&path)?;
+        let loaded = Snapshot::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded.deck, snapshot.deck);
+        assert_eq!(loaded.pending, snapshot.pending);
+        assert_eq!(loaded.state.players_bet, snapshot.state.players_bet);
+        assert!(loaded.raising);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 use crate::core::card::Card;
 
 use super::error::EngineError;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Round {
     Starting,
     Preflop, //Bet
@@ -27,7 +29,7 @@ impl Round {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub round: Round,
     pub community: Vec<Card>,
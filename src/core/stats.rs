@@ -0,0 +1,373 @@
+use std::sync::{Arc, RwLock};
+
+use crate::core::{
+    action::{game_action::GameAction, GameMessage},
+    player::PlayerAction,
+    state::Round,
+};
+
+///The statistics tracker shared between the engine, the players and the UI
+pub type SharedStats = Arc<RwLock<StatsTracker>>;
+
+///Counters of the actions of a seat during a session
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SeatStats {
+    pub hands: u32,
+    ///Hands where the player put money in the pot preflop
+    pub vpip: u32,
+    ///Hands where the player made the first raise, the blinds can't be raised so it's after the
+    ///flop
+    pub open_raises: u32,
+    ///Decisions after a raise of another player in the same round
+    pub faced_raise: u32,
+    ///Raises over the raise of another player in the same round
+    pub reraises: u32,
+
+    ///Bets and raises after the preflop
+    pub aggressive: u32,
+    ///Calls of a bet after the preflop
+    pub calls: u32,
+
+    ///Times the player had to answer a continuation bet
+    pub faced_cbet: u32,
+    pub folded_cbet: u32,
+
+    pub saw_flop: u32,
+    pub showdowns: u32,
+    pub won_showdowns: u32,
+}
+
+impl SeatStats {
+    ///Voluntarily put money in pot, from 0 to 1
+    pub fn vpip(&self) -> f64 {
+        ratio(self.vpip, self.hands)
+    }
+
+    ///Opened the raising in the hands that saw the flop, from 0 to 1
+    pub fn open_raise(&self) -> f64 {
+        ratio(self.open_raises, self.saw_flop)
+    }
+
+    ///Raised again when facing a raise, from 0 to 1
+    pub fn reraise(&self) -> f64 {
+        ratio(self.reraises, self.faced_raise)
+    }
+
+    ///Aggression factor, the number of bets and raises for every call
+    pub fn aggression_factor(&self) -> f64 {
+        if self.calls == 0 {
+            self.aggressive as f64
+        } else {
+            self.aggressive as f64 / self.calls as f64
+        }
+    }
+
+    pub fn fold_to_cbet(&self) -> f64 {
+        ratio(self.folded_cbet, self.faced_cbet)
+    }
+
+    ///Went to showdown after seeing the flop
+    pub fn wtsd(&self) -> f64 {
+        ratio(self.showdowns, self.saw_flop)
+    }
+
+    ///Won money at showdown
+    pub fn wsd(&self) -> f64 {
+        ratio(self.won_showdowns, self.showdowns)
+    }
+}
+
+fn ratio(a: u32, b: u32) -> f64 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f64 / b as f64
+    }
+}
+
+///The state of the hand being played, needed to know the meaning of the actions
+#[derive(Debug, Default, Clone)]
+struct HandTracker {
+    round: Option<Round>,
+    vpip: Vec<usize>,
+    ///Someone raised in this hand
+    opened: bool,
+    ///Someone raised in this round
+    raised: bool,
+
+    last_aggressor: Option<usize>,
+    cbettor: Option<usize>,
+    cbet: bool,
+    answered_cbet: Vec<usize>,
+}
+
+///Keeps the statistics of every seat updated with the messages of the engine
+#[derive(Debug, Default, Clone)]
+pub struct StatsTracker {
+    seats: Vec<SeatStats>,
+    hand: HandTracker,
+}
+
+impl StatsTracker {
+    pub fn seat(&self, i: usize) -> SeatStats {
+        self.seats.get(i).copied().unwrap_or_default()
+    }
+
+    pub fn seats(&self) -> &[SeatStats] {
+        &self.seats
+    }
+
+    pub fn update(&mut self, msg: &GameMessage) {
+        match msg.action {
+            GameAction::RoundChanged { round } => {
+                //Only the public stream is needed, the hands are counted when the preflop starts
+                if round == Round::Preflop {
+                    self.hand = HandTracker::default();
+                    let seats = msg.state.players_money.len();
+                    if self.seats.len() < seats {
+                        self.seats.resize(seats, SeatStats::default());
+                    }
+                    for s in self.seats.iter_mut().take(seats) {
+                        s.hands += 1;
+                    }
+                }
+
+                self.hand.round = Some(round);
+                self.hand.raised = false;
+                self.hand.cbettor = self.hand.last_aggressor.take();
+                self.hand.cbet = false;
+                self.hand.answered_cbet.clear();
+
+                if round == Round::Flop {
+                    let state = &msg.state;
+                    for i in 0..self.seats.len() {
+                        if !state.folded_players.contains(&i) {
+                            self.seats[i].saw_flop += 1;
+                        }
+                    }
+                }
+            }
+            GameAction::PlayedBet { action, i, .. } => self.played_bet(action, i),
+            GameAction::PlayedFolded { i, .. } | GameAction::ErroredPlay { i, .. } => {
+                self.facing_raise(i);
+                if self.facing_cbet(i) {
+                    self.hand.answered_cbet.push(i);
+                    self.seats[i].faced_cbet += 1;
+                    self.seats[i].folded_cbet += 1;
+                }
+            }
+            GameAction::ShowdownHand { i, .. } => {
+                if let Some(s) = self.seats.get_mut(i) {
+                    s.showdowns += 1;
+                }
+            }
+            GameAction::WinGame { i, .. } => {
+                if self.hand.round == Some(Round::Showdown) {
+                    if let Some(s) = self.seats.get_mut(i) {
+                        s.won_showdowns += 1;
+                    }
+                }
+            }
+            GameAction::DealStartHand { .. }
+            | GameAction::DealCommunity { .. }
+            | GameAction::TurnStarted { .. }
+            | GameAction::TimedOut { .. } => {}
+        }
+    }
+
+    fn played_bet(&mut self, action: PlayerAction, i: usize) {
+        if self.seats.len() <= i {
+            return;
+        }
+        let preflop = self.hand.round == Some(Round::Preflop);
+
+        if self.facing_cbet(i) {
+            self.hand.answered_cbet.push(i);
+            self.seats[i].faced_cbet += 1;
+        }
+        let facing_raise = self.facing_raise(i);
+
+        match action {
+            PlayerAction::Raise(_) => {
+                if !self.hand.opened {
+                    self.hand.opened = true;
+                    self.seats[i].open_raises += 1;
+                }
+                if !preflop {
+                    self.seats[i].aggressive += 1;
+                }
+                if facing_raise {
+                    self.seats[i].reraises += 1;
+                } else if !self.hand.raised && self.hand.cbettor == Some(i) {
+                    self.hand.cbet = true;
+                }
+
+                self.hand.raised = true;
+                self.hand.last_aggressor = Some(i);
+            }
+            PlayerAction::Call(amount) => {
+                if !preflop && amount > 0 {
+                    self.seats[i].calls += 1;
+                }
+            }
+            PlayerAction::Fold => {}
+        }
+
+        if preflop && !self.hand.vpip.contains(&i) {
+            self.hand.vpip.push(i);
+            self.seats[i].vpip += 1;
+        }
+    }
+
+    ///Counts the decision if another player raised in this round
+    fn facing_raise(&mut self, i: usize) -> bool {
+        let facing = self.hand.raised && self.hand.last_aggressor != Some(i);
+        if let (true, Some(s)) = (facing, self.seats.get_mut(i)) {
+            s.faced_raise += 1;
+        }
+        facing
+    }
+
+    fn facing_cbet(&self, i: usize) -> bool {
+        self.hand.cbet
+            && self.hand.cbettor != Some(i)
+            && !self.hand.answered_cbet.contains(&i)
+            && i < self.seats.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        card::{Card, Suit, Value},
+        rank::Rank,
+        state::GameState,
+    };
+
+    use super::*;
+
+    fn msg(action: GameAction, folded: &[usize]) -> GameMessage {
+        GameMessage::new(
+            action,
+            GameState {
+                round: Round::Starting,
+                community: vec![],
+                players_bet: vec![0; 3],
+                players_money: vec![100; 3],
+                bet_amount: 1,
+                players_all_in: vec![],
+                folded_players: folded.to_vec(),
+                num_active_players: 3,
+                active_players: vec![0, 1, 2],
+            },
+        )
+    }
+
+    fn deal(stats: &mut StatsTracker) {
+        let c = Card::new(Suit::Club, Value::Two);
+        for i in 0..3 {
+            stats.update(&msg(GameAction::DealStartHand { hand: (c, c), i }, &[]));
+        }
+    }
+
+    fn round(stats: &mut StatsTracker, round: Round, folded: &[usize]) {
+        stats.update(&msg(GameAction::RoundChanged { round }, folded));
+    }
+
+    fn bet(stats: &mut StatsTracker, action: PlayerAction, i: usize) {
+        let all_in = false;
+        stats.update(&msg(GameAction::PlayedBet { action, i, all_in }, &[]));
+    }
+
+    fn fold(stats: &mut StatsTracker, i: usize) {
+        let action = PlayerAction::Fold;
+        stats.update(&msg(GameAction::PlayedFolded { action, i }, &[]));
+    }
+
+    #[test]
+    fn preflop_stats() {
+        let mut stats = StatsTracker::default();
+
+        deal(&mut stats);
+        round(&mut stats, Round::Preflop, &[]);
+        bet(&mut stats, PlayerAction::Call(1), 0);
+        bet(&mut stats, PlayerAction::Call(1), 1);
+        fold(&mut stats, 2);
+
+        deal(&mut stats);
+        round(&mut stats, Round::Preflop, &[]);
+        bet(&mut stats, PlayerAction::Call(1), 0);
+        fold(&mut stats, 1);
+        fold(&mut stats, 2);
+
+        assert_eq!(stats.seat(0).hands, 2);
+        assert_eq!(stats.seat(0).vpip(), 1.0);
+        assert_eq!(stats.seat(1).vpip(), 0.5);
+        assert_eq!(stats.seat(2).vpip(), 0.0);
+    }
+
+    #[test]
+    fn postflop_stats() {
+        let mut stats = StatsTracker::default();
+
+        deal(&mut stats);
+        round(&mut stats, Round::Preflop, &[]);
+        bet(&mut stats, PlayerAction::Call(1), 0);
+        bet(&mut stats, PlayerAction::Call(1), 1);
+        bet(&mut stats, PlayerAction::Call(1), 2);
+
+        round(&mut stats, Round::Flop, &[]);
+        bet(&mut stats, PlayerAction::Raise(10), 0);
+        bet(&mut stats, PlayerAction::Raise(30), 1);
+        bet(&mut stats, PlayerAction::Call(30), 2);
+        bet(&mut stats, PlayerAction::Call(20), 0);
+
+        //Player 1 was the last aggressor, so that bet is a continuation bet
+        round(&mut stats, Round::Turn, &[]);
+        bet(&mut stats, PlayerAction::Call(0), 2);
+        bet(&mut stats, PlayerAction::Call(0), 0);
+        bet(&mut stats, PlayerAction::Raise(50), 1);
+        fold(&mut stats, 2);
+        bet(&mut stats, PlayerAction::Call(50), 0);
+
+        round(&mut stats, Round::River, &[2]);
+        round(&mut stats, Round::Showdown, &[2]);
+        let hand = (
+            Card::new(Suit::Club, Value::Ace),
+            Card::new(Suit::Heart, Value::Ace),
+        );
+        let rank = Rank::OnePair(Value::Ace);
+        stats.update(&msg(GameAction::ShowdownHand { hand, rank, i: 0 }, &[2]));
+        stats.update(&msg(GameAction::ShowdownHand { hand, rank, i: 1 }, &[2]));
+        stats.update(&msg(
+            GameAction::WinGame {
+                rank,
+                i: 1,
+                pot: 200,
+            },
+            &[2],
+        ));
+
+        let s0 = stats.seat(0);
+        let s1 = stats.seat(1);
+        let s2 = stats.seat(2);
+
+        assert_eq!(s0.open_raises, 1);
+        assert_eq!(s0.open_raise(), 1.0);
+        assert_eq!(s1.open_raise(), 0.0);
+        assert_eq!(s1.reraises, 1);
+        assert_eq!(s1.reraise(), 1.0);
+        assert_eq!(s0.faced_raise, 2);
+        assert_eq!(s0.reraise(), 0.0);
+        assert_eq!(s2.faced_raise, 2);
+        assert_eq!(s0.aggression_factor(), 0.5);
+        assert_eq!(s1.aggression_factor(), 2.0);
+        assert_eq!(s2.fold_to_cbet(), 1.0);
+        assert_eq!(s0.fold_to_cbet(), 0.0);
+        assert_eq!(s0.faced_cbet, 1);
+        assert_eq!(s2.wtsd(), 0.0);
+        assert_eq!(s0.wtsd(), 1.0);
+        assert_eq!(s0.wsd(), 0.0);
+        assert_eq!(s1.wsd(), 1.0);
+    }
+}
@@ -4,34 +4,60 @@ use std::thread;
 use std::time::Duration;
 
 use itertools::Itertools;
+use log::warn;
 use rand::Rng;
 use rand::thread_rng;
 use sdl2::event::Event;
-use sdl2::keyboard::Keycode;
 
 use crate::core::action::game_action::GameAction;
 use crate::core::action::mpsc_queue::MpscQueue;
+use crate::core::action::seat_queue::SeatQueue;
 use crate::core::action::GameMessage;
+use crate::core::clock::TimeLimit;
 use crate::core::engine::Engine;
+use crate::core::error::EngineError;
 use crate::core::player;
-use crate::core::player::montecarlo::MontecarloPlayer;
 use crate::core::player::myself::MyselfPlayer;
-use crate::core::player::Player;
 use crate::core::rank::Rank;
+use crate::core::snapshot::{SharedSnapshot, Snapshot};
 use crate::core::state::GameState;
 use crate::core::state::Round;
+use crate::core::stats::SharedStats;
 use crate::game::player_state::PlayerAction;
 use crate::graphic::ui_component::EventReceiver;
-use crate::graphic::{DEAL_DELAY, PLAY_DELAY, START_DELAY, SHOWDOWN_DELAY, ui};
-
+use crate::music::Sound;
+use crate::net::client::{self, SessionEvent};
+use crate::settings::Settings;
+use crate::graphic::{
+    avatar::Avatar, ui, CARD_ANIMATION, CHIPS_ANIMATION, DEAL_DELAY, FLIP_ANIMATION, PLAY_DELAY,
+    SHOWDOWN_DELAY, START_DELAY,
+};
+
+use self::assistant::Assistant;
 use self::player_state::PlayerState;
+use self::profile::{Profile, PROFILE_PATH};
+use self::setup::{GameSetup, Seat};
+use self::summary::{HandReview, HandSummary};
+use self::save::{SavedGame, SAVE_PATH};
 
+pub mod assistant;
 pub mod player_state;
+pub mod profile;
+pub mod save;
 pub mod self_controller;
 pub mod game_render;
+pub mod menu;
+pub mod setup;
+pub mod summary;
 
 pub static DEBUG: AtomicBool = AtomicBool::new(false);
 
+///Time the players have to decide in a local game
+pub const TIME_LIMIT: TimeLimit = TimeLimit {
+    decision: Duration::from_secs(30),
+    bank: Duration::from_secs(30),
+};
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum GamePhase {
     #[default]
@@ -40,6 +66,12 @@ pub enum GamePhase {
     Pause,
     Showdown(usize),
     Ended(Rank, usize, i32),
+    ///Waiting to join a remote table, or to join it again after losing the connection
+    Connecting,
+    ///The remote table was closed
+    Closed,
+    ///The profile of the local player, opened from the pause menu
+    Profile,
 }
 
 #[derive(Default)]
@@ -50,23 +82,156 @@ pub struct Game {
     myself: usize,
     game_rx: Option<mpsc::Receiver<GameMessage>>,
     player_tx: Option<mpsc::Sender<PlayerAction>>,
+    ///The player went back to the main menu, the game can be dropped
+    left: bool,
+    ///The thread of the local engine, it ends with the hand
+    engine: Option<thread::JoinHandle<Result<Vec<i32>, EngineError>>>,
     game_state: Option<GameState>,
+    stats: SharedStats,
+
+    ///The connection with a remote table, when the engine doesn't run in this process
+    session: Option<mpsc::Receiver<SessionEvent>>,
+    ///The phase to go back to after joining the table again
+    resume: GamePhase,
+    ///The stacks sent by the table server at the end of the hand
+    hand_stacks: Option<Vec<i32>>,
+
+    ///The state of the local engine before the last decision
+    snapshot: SharedSnapshot,
+    ///The hand is continued from here when the game starts
+    resume_from: Option<Snapshot>,
+    ///A message shown in the pause menu
+    notice: Option<String>,
+    ///The local player, updated and saved after every hand
+    profile: Option<Profile>,
+    ///The table chosen in the setup screen
+    setup: GameSetup,
+    settings: Settings,
+    ///The sound effects of the events, played by the audio of the frontend
+    sounds: Vec<Sound>,
+    ///The odds of the local player, shown when the assistant is on
+    assistant: Assistant,
+    ///The shown hands, the pots and the actions of the last hand
+    summary: HandSummary,
+    review: HandReview,
 
     pub delay: Duration,
-    turn: usize,
 }
 
 impl Game {
-    //TODO: New players that aren't default
-    pub fn new(default_players: bool) -> Self {
-        let mut game = Game::default();
-        game.turn = usize::MAX;
+    pub fn new(setup: GameSetup) -> Self {
+        let mut game = Game {
+            setup,
+            ..Default::default()
+        };
+        game.seat_players();
+
+        game
+    }
+
+    ///Plays in a table server instead of running the engine, the players are the ones of the table
+    pub fn connect(addr: String, name: String) -> Self {
+        let (game_tx, game_rx) = mpsc::channel();
+        let (player_tx, player_rx) = mpsc::channel();
 
-        if default_players {
-            game.default_players();
+        Game {
+            phase: GamePhase::Connecting,
+            resume: GamePhase::Playing,
+            game_rx: Some(game_rx),
+            player_tx: Some(player_tx),
+            session: Some(client::spawn_session(addr, name, game_tx, player_rx)),
+            ..Default::default()
         }
+    }
 
-        game
+    ///Uses the animation speed and the keys of the settings
+    pub fn with_settings(mut self, settings: Settings) -> Self {
+        self.ui.player_controller.keys = settings.keys;
+        self.settings = settings;
+        self
+    }
+
+    ///Seats the local player with the name and bankroll of the profile, a saved hand keeps its money
+    pub fn with_profile(mut self, profile: Profile) -> Self {
+        if let Some(me) = self.players.as_mut().and_then(|p| p.get_mut(self.myself)) {
+            me.name = profile.name.clone();
+            me.avatar = Avatar::or_generated(profile.avatar.as_deref(), None, &profile.name);
+            if self.resume_from.is_none() {
+                me.cash = profile.bankroll.max(1);
+            }
+        }
+
+        self.profile = Some(profile);
+        self
+    }
+
+    ///Names and pictures the opponents with the seats of the setup screen, in the order of the
+    ///table starting after the local player
+    pub fn with_seats(mut self, seats: &[Seat]) -> Self {
+        let bot = Some(self.setup.difficulty);
+        if let Some(players) = &mut self.players {
+            let n = players.len();
+            let opponents = (1..n).map(|k| (self.myself + k) % n);
+            for (i, seat) in opponents.zip(seats) {
+                let p = &mut players[i];
+                if !seat.name.trim().is_empty() {
+                    p.name = seat.name.trim().to_string();
+                }
+                p.avatar = Avatar::or_generated(seat.avatar.as_deref(), bot, &p.name);
+            }
+        }
+
+        self
+    }
+
+    ///Continues a saved game from the decision where it was stopped
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<Self> {
+        let saved = SavedGame::load(path)?;
+        saved.check()?;
+        let state = &saved.snapshot.state;
+
+        let players = saved
+            .names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| PlayerState {
+                name: name.clone(),
+                avatar: saved
+                    .avatars
+                    .get(i)
+                    .cloned()
+                    .unwrap_or_else(|| Avatar::generated(None, name)),
+                cash: state.players_money.get(i).copied().unwrap_or_default(),
+                bet: state.players_bet.get(i).copied().unwrap_or_default(),
+                folded: state.folded_players.contains(&i),
+                all_in: state.players_all_in.contains(&i),
+                ..Default::default()
+            })
+            .collect_vec();
+
+        Ok(Game {
+            players: Some(players),
+            myself: saved.myself,
+            setup: saved.setup,
+            resume_from: Some(saved.snapshot),
+            ..Default::default()
+        })
+    }
+
+    ///Saves the hand being played, to be continued with [`Game::load`]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        let snapshot = self.snapshot.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        match (snapshot, &self.players) {
+            (Some(snapshot), Some(players)) if self.session.is_none() => SavedGame {
+                names: players.iter().map(|p| p.name.clone()).collect(),
+                avatars: players.iter().map(|p| p.avatar.clone()).collect(),
+                myself: self.myself,
+                snapshot,
+                setup: self.setup,
+            }
+            .save(path),
+            _ => Err(std::io::Error::other("There's no hand to save")),
+        }
     }
 
     pub fn early_update(&mut self) {
@@ -78,6 +243,16 @@ impl Game {
     }
 
     pub fn update(&mut self, delta: &Duration) {
+        self.update_session();
+        self.check_engine();
+        self.ui.update(delta);
+
+        if let Some(players) = &mut self.players {
+            for t in players.iter_mut().filter_map(|p| p.time_left.as_mut()) {
+                *t = t.saturating_sub(*delta);
+            }
+        }
+
         if !self.delay.is_zero() {
             if self.delay <= *delta {
                 self.delay = Duration::ZERO;
@@ -94,14 +269,36 @@ impl Game {
             GamePhase::Playing => {
                 if let Some(rx) = &self.game_rx {
                     if let Ok(msg) = rx.try_recv() {
+                        //The stats of a local game are kept by the engine
+                        if self.session.is_some() {
+                            if let Ok(mut stats) = self.stats.write() {
+                                stats.update(&msg);
+                            }
+                        }
                         self.update_player_state(msg);
                     }
                 }
 
                 if let Some(player_states) = &mut self.players {
+                    if let Ok(stats) = self.stats.read() {
+                        for (i, p) in player_states.iter_mut().enumerate() {
+                            p.stats = stats.seat(i);
+                        }
+                    }
                     self.ui.update_states(player_states, self.myself);
                 }
 
+                self.update_assistant();
+
+                //The pre-action is played when the engine asks for the decision
+                if let Some(act) = self.ui.player_controller.take_pre_action() {
+                    if let Some(tx) = &self.player_tx {
+                        if tx.send(act).is_err() {
+                            warn!("Couldn't send the pre-action to the engine");
+                        }
+                    }
+                }
+
                 if let Some(state) = &self.game_state {
                     self.ui.community.pot = state.players_bet.iter().sum();
                 }
@@ -109,11 +306,84 @@ impl Game {
             GamePhase::Showdown(..) => {}
             GamePhase::Pause => {}
             GamePhase::Ended(..) => {}
+            GamePhase::Connecting => {}
+            GamePhase::Closed => {}
+            GamePhase::Profile => {}
+        }
+    }
+
+    ///Asks for the odds of the local player when the hand changes, they are computed in another thread
+    fn update_assistant(&mut self) {
+        let advice = match (&self.players, &self.game_state) {
+            (Some(players), Some(state)) if self.settings.assistant => {
+                let me = &players[self.myself];
+                match me.hand {
+                    Some(hand) if !me.folded => {
+                        self.assistant
+                            .request(hand, state, self.myself, self.settings.sim_rounds);
+                        self.assistant.advice().cloned()
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        self.ui.player_controller.advice = advice;
+    }
+
+    fn update_session(&mut self) {
+        let events = match &self.session {
+            Some(session) => session.try_iter().collect_vec(),
+            None => return,
+        };
+
+        for event in events {
+            match event {
+                SessionEvent::Joined {
+                    seat,
+                    names,
+                    stacks,
+                } => {
+                    if self.players.is_none() {
+                        let players = names
+                            .into_iter()
+                            .zip(stacks)
+                            .map(|(name, cash)| PlayerState {
+                                avatar: Avatar::generated(None, &name),
+                                name,
+                                cash,
+                                ..Default::default()
+                            })
+                            .collect_vec();
+                        self.players = Some(players);
+                        self.myself = seat;
+
+                        self.phase = GamePhase::Start;
+                        self.start();
+                    } else {
+                        self.phase = self.resume;
+                    }
+                }
+                SessionEvent::HandEnded { stacks } => self.hand_stacks = Some(stacks),
+                SessionEvent::Lost => {
+                    if self.phase != GamePhase::Connecting {
+                        self.resume = self.phase;
+                        self.phase = GamePhase::Connecting;
+                    }
+                }
+                SessionEvent::Closed => self.phase = GamePhase::Closed,
+            }
         }
     }
 
     pub fn start(&mut self) {
-        self.delay = START_DELAY;
+        self.delay = self.settings.animation(START_DELAY);
+
+        //The remote table starts the hands by itself
+        if self.session.is_some() {
+            self.start_ui();
+            return;
+        }
 
         let (game_tx, game_rx) = mpsc::channel();
         self.game_rx = Some(game_rx);
@@ -122,29 +392,62 @@ impl Game {
         self.player_tx = Some(player_tx);
 
         let this = self.myself;
+        let stats = self.stats.clone();
+        let snapshot = self.snapshot.clone();
+        let resume_from = self.resume_from.take();
+        let setup = self.setup;
 
         //Start engine thread
         if let Some(player_states) = self.players.clone() {
-            thread::spawn(move || {
-                let queue = Box::new(MpscQueue::new(game_tx));
+            self.engine = Some(thread::spawn(move || {
+                let queue = Box::new(SeatQueue::new(Box::new(MpscQueue::new(game_tx)), this));
                 let mut players = player_states
                     .iter()
-                    .map(|_| Box::<MontecarloPlayer>::default() as Box<dyn Player>)
+                    .map(|_| setup.difficulty.bot(setup.sim_rounds))
                     .collect_vec();
                 players[this] = Box::new(MyselfPlayer::new(player_rx));
 
-                let engine = Engine::new(players, queue).expect("Cannot create poker engine");
+                let mut engine = Engine::with_stats(players, queue, stats)?.with_snapshots(snapshot);
+                if let Some(limit) = setup.time_limit {
+                    engine = engine.with_time_limit(limit);
+                }
 
-                let players_money = player_states.iter().map(|p| p.cash).collect_vec();
-                engine.run(players_money, 1).unwrap();
-            });
+                match resume_from {
+                    Some(snapshot) => engine.resume_from(snapshot).and_then(|e| e.run_resumed()),
+                    None => {
+                        let players_money = player_states.iter().map(|p| p.cash).collect_vec();
+                        engine.run(players_money, setup.blind)
+                    }
+                }
+            }));
         }
 
-        //Start UI
+        self.start_ui();
+    }
+
+    ///Pauses the game with the error if the engine stopped in the middle of the hand
+    fn check_engine(&mut self) {
+        if !self.engine.as_ref().is_some_and(|e| e.is_finished()) {
+            return;
+        }
+
+        let error = match self.engine.take().map(|e| e.join()) {
+            Some(Ok(Err(e))) => e.to_string(),
+            Some(Err(_)) => "the engine crashed".to_string(),
+            _ => return,
+        };
+        warn!("The hand stopped: {error}");
+        self.notice = Some(format!("The hand stopped: {error}"));
+        self.phase = GamePhase::Pause;
+    }
+
+    fn start_ui(&mut self) {
         if let Some(player_states) = &self.players {
-            self.ui
-                .start(player_states, self.myself)
-                .expect("Couldn't start the UI");
+            if let Err(e) = self.ui.start(player_states, self.myself, self.setup.blind) {
+                warn!("Couldn't start the table: {e}");
+                self.notice = Some(format!("Couldn't start the table: {e}"));
+                self.phase = GamePhase::Pause;
+            }
         }
     }
 
@@ -152,137 +455,293 @@ impl Game {
         if let GamePhase::Showdown(..) = self.phase {
             self.phase = GamePhase::Playing;
         }
-        else if let (Some(players), Some(state))= (&mut self.players, &self.game_state) {
-            update_turn(&mut self.turn, state, players);
-        }
     }
 
     fn update_player_state(&mut self, msg: GameMessage) {
         let state = msg.state;
         let mut rng = thread_rng();
 
+        //The decision of the local player was played, the next one can be sent
+        if let GameAction::PlayedBet { i, .. }
+        | GameAction::PlayedFolded { i, .. }
+        | GameAction::ErroredPlay { i, .. } = &msg.action
+        {
+            if *i == self.myself {
+                self.ui.player_controller.end_turn();
+            }
+        }
+
+        //The entries of the log, they are also the timeline of the hand
+        let mut entries = vec![];
+
         if let Some(players) = &mut self.players {
             match msg.action {
                 GameAction::DealStartHand { hand, i } => {
                     if i == self.myself {
                         players[i].hand = Some(hand);
+                        self.sounds.push(Sound::Deal);
+                        //Only the own cards are dealt to the table, so they start the hand
+                        self.ui.log.push("--- New hand ---");
+                        self.summary = HandSummary::new(players.iter().map(|p| p.cash + p.bet).collect());
+                        entries.push(format!("Your hand: {} {}", hand.0, hand.1));
+                        self.ui.deal(i, self.settings.animation(CARD_ANIMATION));
                     }
                 }
                 GameAction::RoundChanged { round } => {
                     for p in players.iter_mut() {
                         p.can_raise = round != Round::Preflop;
+                        p.turn = false;
                     }
-                    if round >= Round::Preflop && round < Round::Complete {
-                        self.turn = 0;
-                        while self.turn < players.len() {
-                            if !state.folded_players.contains(&self.turn) && !state.players_all_in.contains(&self.turn) {
-                                players[self.turn].turn = true;
-                                break;
-                            }
-                            self.turn += 1;
+                    //The deals of the opponents are private, their cards slide face down when the
+                    //blinds start
+                    if round == Round::Preflop {
+                        for i in (0..players.len()).filter(|&i| i != self.myself) {
+                            self.ui.deal(i, self.settings.animation(CARD_ANIMATION));
                         }
                     }
                 }
+                //Only the seat the engine is waiting for can play
+                GameAction::TurnStarted { i, time } => {
+                    for p in players.iter_mut() {
+                        p.turn = false;
+                    }
+                    players[i].turn = true;
+                    //The engine started the clock before the delays of the table
+                    players[i].time_left = time.map(|t| t.left());
+                    players[i].time_total = time.map_or(Duration::ZERO, |t| t.total);
+                }
+                GameAction::TimedOut { i, .. } => {
+                    players[i].turn = false;
+                    players[i].time_left = None;
+                    entries.push(format!("{} ran out of time", players[i].name));
+                }
                 GameAction::DealCommunity { card } => {
-                    self.ui.community.add_card(card);
-                    self.delay = DEAL_DELAY;
+                    self.ui.community.add_card(card, self.settings.animation(CARD_ANIMATION));
+                    let board = self.ui.community.cards.iter().flatten().collect_vec();
+                    match board.len() {
+                        3 => entries.push(format!("Flop: {}", board.iter().join(" "))),
+                        4 => entries.push(format!("Turn: {card}")),
+                        5 => entries.push(format!("River: {card}")),
+                        _ => (),
+                    }
+                    self.sounds.push(Sound::Deal);
+                    self.delay = self.settings.animation(DEAL_DELAY);
                 }
                 GameAction::PlayedBet { action, i, all_in } => {
                     match action {
                         player::PlayerAction::Raise(a) | player::PlayerAction::Call(a) => {
+                            self.sounds.push(if all_in {
+                                Sound::AllIn
+                            } else if a == 0 {
+                                Sound::Check
+                            } else {
+                                Sound::Chips
+                            });
+                            if a > 0 {
+                                self.ui.bet_chips(i, a, self.settings.animation(CHIPS_ANIMATION));
+                            }
                             players[i].cash -= a;
                             players[i].bet += a;
                             players[i].all_in = all_in;
+
+                            let name = &players[i].name;
+                            let mut entry = match action {
+                                player::PlayerAction::Call(0) => format!("{name} checks"),
+                                player::PlayerAction::Call(a) => format!("{name} calls {a}€"),
+                                _ => format!("{name} raises to {}€", players[i].bet),
+                            };
+                            if all_in {
+                                entry.push_str(", all-in");
+                            }
+                            entries.push(entry);
                         }
                         player::PlayerAction::Fold => panic!("A fold is not a bet"),
                     }
+                    players[i].turn = false;
+                    players[i].time_left = None;
 
-                    self.turn = i;
-                    self.delay = PLAY_DELAY.mul_f32(rng.gen_range(0.5..=1.0));
+                    self.delay = self.settings.animation(PLAY_DELAY).mul_f32(rng.gen_range(0.5..=1.0));
                 }
                 GameAction::PlayedFolded { action, i } => {
                     match action {
-                        player::PlayerAction::Fold => players[i].folded = true,
+                        player::PlayerAction::Fold => {
+                            players[i].folded = true;
+                            self.sounds.push(Sound::Fold);
+                            entries.push(format!("{} folds", players[i].name));
+                        }
                         _ => panic!("A bet is not a fold"),
                     }
-                    
-                    self.turn = i;
-                    self.delay = PLAY_DELAY.mul_f32(rng.gen_range(0.5..=1.0));
+                    players[i].turn = false;
+                    players[i].time_left = None;
+
+                    self.delay = self.settings.animation(PLAY_DELAY).mul_f32(rng.gen_range(0.5..=1.0));
                 }
                 GameAction::ErroredPlay { error, i } => {
-                    println!("{error}");
+                    entries.push(format!("{}: {error}", players[i].name));
+                    players[i].turn = false;
+                    players[i].time_left = None;
 
-                    self.turn = i;
-                    self.delay = PLAY_DELAY.mul_f32(rng.gen_range(0.5..=1.0));
+                    self.delay = self.settings.animation(PLAY_DELAY).mul_f32(rng.gen_range(0.5..=1.0));
                 }
                 GameAction::ShowdownHand { hand, rank, i } => {
+                    if players[i].hand.is_none() {
+                        self.ui.flip(i, self.settings.animation(FLIP_ANIMATION));
+                    }
                     players[i].hand = Some(hand);
                     players[i].rank = Some(rank);
+                    entries.push(format!(
+                        "{} shows {} {}, {}",
+                        players[i].name,
+                        hand.0,
+                        hand.1,
+                        rank.name()
+                    ));
+
+                    if let Err(e) = self.summary.show(i, hand, rank, &state.community) {
+                        warn!("Couldn't find the best cards of the hand: {e}");
+                    }
 
-                    self.turn = i;
-                    update_turn(&mut self.turn, &state, players);
                     self.phase = GamePhase::Showdown(i);
-                    self.delay = SHOWDOWN_DELAY;
+                    self.delay = self.settings.animation(SHOWDOWN_DELAY);
                 }
                 GameAction::WinGame { rank, i, pot } => {
+                    if let Some(profile) = &mut self.profile {
+                        let won = i == self.myself;
+                        let bankroll = players[self.myself].cash + if won { pot } else { 0 };
+                        profile.record_hand(bankroll, won.then_some(pot));
+                        if let Err(e) = profile.save(PROFILE_PATH) {
+                            warn!("Couldn't save the profile: {e}");
+                        }
+                    }
+
                     players[i].turn = true;
                     self.phase = GamePhase::Ended(rank, i, pot);
+                    self.summary.award(i, pot);
+                    entries.push(format!("{} wins {pot}€ with {}", players[i].name, rank.name()));
+                    self.sounds.push(Sound::Win);
+                    self.ui.win_chips(i, pot, self.settings.animation(CHIPS_ANIMATION));
                 }
             };
         }
 
+        for entry in entries {
+            self.ui.log.push(&entry);
+            self.summary.timeline.push(entry);
+        }
+
         //Set state to the engine's state at the end
         self.game_state = Some(state);
     }
 
-    pub fn default_players(&mut self) {
+    fn seat_players(&mut self) {
         let mut rng = rand::thread_rng();
-        let max_p = 8;
+        let max_p = self.setup.opponents + 1;
         self.players = Some(Vec::new());
         for i in 0..max_p {
+            let name = format!("Player{}", i + 1);
             self.players.as_mut().unwrap().push(PlayerState {
-                name: format!("Player{}", i + 1),
+                avatar: Avatar::generated(Some(self.setup.difficulty), &name),
+                name,
                 bet: 0,
-                cash: 100000,
+                cash: self.setup.stack,
                 hand: None,
                 rank: None,
                 can_raise: false,
                 folded: false,
                 all_in: false,
                 turn: false,
+                stats: Default::default(),
+                time_left: None,
+                time_total: Duration::ZERO,
             });
         }
 
         self.myself = rng.gen_range(0..max_p);
-        self.players.as_mut().unwrap()[self.myself].name = "Me".to_string();
+        let me = &mut self.players.as_mut().unwrap()[self.myself];
+        me.name = "Me".to_string();
+        me.avatar = Avatar::generated(None, &me.name);
+    }
+
+    ///The sounds of the events since the last call
+    pub fn take_sounds(&mut self) -> Vec<Sound> {
+        std::mem::take(&mut self.sounds)
     }
 
     pub fn is_running(&self) -> bool {
         self.phase == GamePhase::Playing
     }
+
+    ///The main menu has to be shown instead of the game
+    pub fn has_left(&self) -> bool {
+        self.left
+    }
 }
 
 impl EventReceiver<Result<(), String>> for Game {
     fn handle_event(&mut self, event: &Event) -> Result<(), String> {
+        let keys = self.settings.keys;
+
         #[allow(clippy::single_match)]
         match event {
             Event::KeyDown {
                 keycode: Some(key), ..
-            } => match key {
-                Keycode::P => {
+            } => match *key {
+                k if k == keys.pause.0 => {
                     if self.phase == GamePhase::Pause {
                         self.phase = GamePhase::Playing;
                     } else if self.phase == GamePhase::Playing {
                         self.phase = GamePhase::Pause;
+                        self.notice = None;
+                    }
+                }
+                k if k == keys.profile.0 && self.phase == GamePhase::Pause && self.profile.is_some() => {
+                    self.phase = GamePhase::Profile;
+                }
+                k if k == keys.profile.0 && self.phase == GamePhase::Profile => self.phase = GamePhase::Pause,
+                k if k == keys.menu.0 && matches!(self.phase, GamePhase::Pause | GamePhase::Closed) => {
+                    //The engine and the session end when they can't reach the game
+                    self.player_tx = None;
+                    self.game_rx = None;
+                    self.left = true;
+                }
+                k if k == keys.save.0 && self.phase == GamePhase::Pause => {
+                    self.notice = Some(match self.save(SAVE_PATH) {
+                        Ok(()) => "The hand was saved".to_string(),
+                        Err(e) => e.to_string(),
+                    });
+                }
+                k if k == keys.load.0 && self.phase == GamePhase::Pause && self.session.is_none() => {
+                    match Game::load(SAVE_PATH) {
+                        Ok(mut game) => {
+                            //Without the table the engine of this hand folds the local player
+                            //and ends
+                            self.player_tx = None;
+                            self.game_rx = None;
+
+                            game = game.with_settings(self.settings.clone());
+                            if let Some(profile) = self.profile.take() {
+                                game = game.with_profile(profile);
+                            }
+                            game.start();
+                            *self = game;
+                            return Ok(());
+                        }
+                        Err(e) => self.notice = Some(e.to_string()),
                     }
                 }
-                Keycode::D => {
+                k if k == keys.assistant.0 => {
+                    self.settings.assistant = !self.settings.assistant;
+                    self.assistant.clear();
+                }
+                k if k == keys.debug.0 => {
                     let d = !DEBUG.load(std::sync::atomic::Ordering::Relaxed);
                     DEBUG.store(d, std::sync::atomic::Ordering::Relaxed);
                 },
-                Keycode::Return => {
+                k if k == keys.next_hand.0 => {
                     if let (GamePhase::Ended(_, idx, pot), Some(players)) = (self.phase, &mut self.players) {
                         self.game_state = None;
+                        self.ui.new_hand();
+                        self.review.close();
                         for (i, p) in players.iter_mut().enumerate() {
                             p.bet = 0;
                             p.cash = if p.cash <= 0 {1} else {p.cash};
@@ -292,14 +751,17 @@ impl EventReceiver<Result<(), String>> for Game {
                             p.folded = false;
                             p.all_in = false;
                             p.turn = false;
-                            for c in &mut self.ui.community.cards {
-                                *c = None;
-                            }
 
                             if i == idx {
                                 p.cash += pot;
                             }
                         }
+                        //The table server knows the real stacks, with the split pots
+                        if let Some(stacks) = self.hand_stacks.take() {
+                            for (p, cash) in players.iter_mut().zip(stacks) {
+                                p.cash = cash;
+                            }
+                        }
                         self.phase = GamePhase::Start;
                         self.start();
                     }
@@ -310,6 +772,11 @@ impl EventReceiver<Result<(), String>> for Game {
         }
 
         if let GamePhase::Ended(..) = self.phase {
+            self.review.handle_event(event, &self.summary);
+            return Ok(());
+        }
+        //The action keys do nothing while the game is paused or between hands
+        if self.phase != GamePhase::Playing {
             return Ok(());
         }
 
@@ -322,16 +789,3 @@ impl EventReceiver<Result<(), String>> for Game {
         Ok(())
     }
 }
-
-fn update_turn(turn: &mut usize, state: &GameState, players: &mut [PlayerState]) {
-    players[*turn].turn = false;
-    if *turn < players.len() { *turn += 1; }
-
-    while *turn < players.len() {
-        if !state.folded_players.contains(turn) && !state.players_all_in.contains(turn) {
-            players[*turn].turn = true;
-            break;
-        }
-        *turn += 1;
-    }
-}
@@ -0,0 +1,291 @@
+use std::sync::mpsc;
+use std::thread;
+
+use crate::core::{
+    card::Card,
+    draw::{Draw, DrawKind},
+    error::EngineError,
+    hand::Hand,
+    player::{montecarlo::MontecarloPlayer, Player, PlayerHand},
+    rank::{Rank, Rankable},
+    state::{GameState, Round},
+};
+
+///How much better than the average hand the equity has to be to suggest a raise
+const RAISE_EDGE: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suggestion {
+    Fold,
+    Check,
+    Call,
+    Raise,
+}
+
+impl Suggestion {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suggestion::Fold => "FOLD",
+            Suggestion::Check => "CHECK",
+            Suggestion::Call => "CALL",
+            Suggestion::Raise => "RAISE",
+        }
+    }
+}
+
+///What the training overlay shows about the decision of the local player
+#[derive(Debug, Clone, PartialEq)]
+pub struct Advice {
+    ///The best hand with the cards on the table
+    pub made: Rank,
+    pub draw: DrawKind,
+    pub outs: usize,
+    ///Probability of winning against the opponents that haven't folded
+    pub equity: f64,
+    pub opponents: usize,
+    ///Part of the final pot that the call costs
+    pub pot_odds: f64,
+    pub suggestion: Suggestion,
+}
+
+impl Advice {
+    ///Simulates the rest of the hand, it takes a while with many rounds
+    pub fn new(
+        hand: PlayerHand,
+        state: &GameState,
+        i: usize,
+        sim_rounds: i32,
+    ) -> Result<Self, EngineError> {
+        let mut player = MontecarloPlayer::default().with_sim_rounds(sim_rounds);
+        player.give_cards(hand);
+        let (equity, _) = player.montecarlo_sim(state, i, sim_rounds.max(1))?;
+
+        let draw = Draw::new(hand, &state.community);
+        let opponents = (state.players_money.len() - state.folded_players.len()).saturating_sub(1);
+        let diff = state.bet_amount - state.players_bet.get(i).copied().unwrap_or_default();
+        let pot_odds = pot_odds(state.players_bet.iter().sum(), diff);
+        //The blinds are only called
+        let can_raise = state.round != Round::Preflop;
+
+        Ok(Advice {
+            made: made_hand(hand, &state.community)?,
+            draw: draw.kind,
+            outs: draw.outs.len(),
+            equity,
+            opponents,
+            pot_odds,
+            suggestion: suggest(equity, pot_odds, diff, opponents, can_raise),
+        })
+    }
+}
+
+///The cost of the call over the pot after calling, 0 when there's nothing to call
+pub fn pot_odds(pot: i32, diff: i32) -> f64 {
+    if diff <= 0 {
+        return 0.0;
+    }
+    diff as f64 / (pot + diff) as f64
+}
+
+///Calls when the equity pays the pot odds and raises when it's well over the fair share
+///<br>The blinds can't be raised, there the strong hands call or check
+pub fn suggest(
+    equity: f64,
+    pot_odds: f64,
+    diff: i32,
+    opponents: usize,
+    can_raise: bool,
+) -> Suggestion {
+    let share = equity * (opponents + 1) as f64;
+    if share >= RAISE_EDGE && can_raise {
+        Suggestion::Raise
+    } else if diff <= 0 {
+        Suggestion::Check
+    } else if equity >= pot_odds || share >= RAISE_EDGE {
+        Suggestion::Call
+    } else {
+        Suggestion::Fold
+    }
+}
+
+///Before the flop there are only pairs and high cards
+fn made_hand(hand: PlayerHand, community: &[Card]) -> Result<Rank, EngineError> {
+    if community.len() >= 3 {
+        return Hand::new_from_hand(hand, community).rank();
+    }
+
+    Ok(if hand.0.value == hand.1.value {
+        Rank::OnePair(hand.0.value)
+    } else {
+        Rank::HighCard(hand.0.value.max(hand.1.value))
+    })
+}
+
+///A situation of the hand to simulate
+struct Request {
+    id: u64,
+    hand: PlayerHand,
+    state: GameState,
+    i: usize,
+    sim_rounds: i32,
+}
+
+///Computes the advice in another thread every time the situation of the hand changes
+#[derive(Default)]
+pub struct Assistant {
+    ///The hand, the community cards, the bet and the folds of the last request
+    last: Option<(PlayerHand, usize, i32, usize)>,
+    ///The id of the last request, the advice of the older ones is discarded
+    id: u64,
+    worker: Option<(mpsc::Sender<Request>, mpsc::Receiver<(u64, Advice)>)>,
+    advice: Option<Advice>,
+}
+
+impl Assistant {
+    ///Starts a simulation if anything changed since the last one
+    pub fn request(&mut self, hand: PlayerHand, state: &GameState, i: usize, sim_rounds: i32) {
+        let key = (
+            hand,
+            state.community.len(),
+            state.bet_amount,
+            state.folded_players.len(),
+        );
+        if self.last == Some(key) {
+            return;
+        }
+        self.last = Some(key);
+        self.advice = None;
+        self.id += 1;
+
+        let (tx, _) = self.worker.get_or_insert_with(spawn_worker);
+        tx.send(Request {
+            id: self.id,
+            hand,
+            state: state.clone(),
+            i,
+            sim_rounds,
+        })
+        .ok();
+    }
+
+    ///The advice for the current situation, once it's computed
+    pub fn advice(&mut self) -> Option<&Advice> {
+        if let Some((_, rx)) = &self.worker {
+            for (id, advice) in rx.try_iter() {
+                if id == self.id {
+                    self.advice = Some(advice);
+                }
+            }
+        }
+        self.advice.as_ref()
+    }
+
+    ///Forgets the advice, at the end of the hand or when the player folds
+    pub fn clear(&mut self) {
+        self.last = None;
+        self.advice = None;
+        self.id += 1;
+    }
+}
+
+///A single thread simulates the requests, skipping the ones that were replaced while it was busy
+fn spawn_worker() -> (mpsc::Sender<Request>, mpsc::Receiver<(u64, Advice)>) {
+    let (request_tx, request_rx) = mpsc::channel::<Request>();
+    let (advice_tx, advice_rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        while let Ok(request) = request_rx.recv() {
+            let request = request_rx.try_iter().last().unwrap_or(request);
+            let advice = Advice::new(request.hand, &request.state, request.i, request.sim_rounds);
+            if let Ok(advice) = advice {
+                if advice_tx.send((request.id, advice)).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    (request_tx, advice_rx)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{
+        card::{Suit, Value},
+        state::Round,
+    };
+
+    use super::*;
+
+    #[test]
+    fn odds_and_suggestions() {
+        assert_eq!(pot_odds(300, 0), 0.0);
+        assert_eq!(pot_odds(300, 100), 0.25);
+
+        assert_eq!(suggest(0.2, 0.25, 100, 3, true), Suggestion::Fold);
+        assert_eq!(suggest(0.3, 0.25, 100, 3, true), Suggestion::Call);
+        assert_eq!(suggest(0.3, 0.0, 0, 3, true), Suggestion::Check);
+        assert_eq!(suggest(0.8, 0.25, 100, 1, true), Suggestion::Raise);
+
+        //The blinds can't be raised
+        assert_eq!(suggest(0.8, 0.25, 100, 1, false), Suggestion::Call);
+        assert_eq!(suggest(0.8, 0.0, 0, 1, false), Suggestion::Check);
+    }
+
+    fn nuts() -> (PlayerHand, GameState) {
+        let hand = (Card::new(Suit::Heart, Value::Ace), Card::new(Suit::Heart, Value::King));
+        let state = GameState {
+            round: Round::Flop,
+            community: vec![
+                Card::new(Suit::Heart, Value::Queen),
+                Card::new(Suit::Heart, Value::Jack),
+                Card::new(Suit::Heart, Value::Ten),
+            ],
+            players_bet: vec![10, 10],
+            players_money: vec![100, 100],
+            bet_amount: 10,
+            players_all_in: vec![],
+            folded_players: vec![],
+            num_active_players: 2,
+            active_players: vec![0, 1],
+        };
+
+        (hand, state)
+    }
+
+    #[test]
+    fn advice_for_the_nuts() -> Result<(), EngineError> {
+        let (hand, state) = nuts();
+        let advice = Advice::new(hand, &state, 0, 100)?;
+        assert_eq!(advice.made, Rank::StraightFlush(Value::Ace, Suit::Heart));
+        assert_eq!(advice.equity, 1.0);
+        assert_eq!(advice.opponents, 1);
+        assert_eq!(advice.suggestion, Suggestion::Raise);
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_the_last_request_is_advised() {
+        let (hand, state) = nuts();
+        let raised = GameState {
+            players_bet: vec![10, 20],
+            bet_amount: 20,
+            ..state.clone()
+        };
+
+        let mut assistant = Assistant::default();
+        assistant.request(hand, &state, 0, 100);
+        assistant.request(hand, &raised, 0, 100);
+
+        let start = std::time::Instant::now();
+        let advice = loop {
+            if let Some(advice) = assistant.advice() {
+                break advice.clone();
+            }
+            assert!(start.elapsed().as_secs() < 10);
+            thread::sleep(std::time::Duration::from_millis(5));
+        };
+        assert_eq!(advice.pot_odds, 0.25);
+    }
+}
@@ -1,12 +1,16 @@
 use sdl2::{pixels::Color, rect::{Rect, Point}};
 
-use crate::{game::{Game, GamePhase}, core::card::{Card, Value}, graphic::{ui_component::Drawable, SDL2Graphics, font::DEFAULT_FONT, WIDTH, START_DELAY, HEIGHT}};
+use crate::{game::{Game, GamePhase}, graphic::{ui_component::Drawable, SDL2Graphics, font::DEFAULT_FONT, WIDTH, START_DELAY, HEIGHT}};
+
+use itertools::Itertools;
+
+use crate::graphic::tween::{draw_card, Pose};
 
 use super::player_state::PlayerState;
+use super::summary::HandSummary;
 
-pub const CARD_SPRITE_RATIO: f32 = SPRITE_HEIGHT as f32 / SPRITE_WIDTH as f32;
-const SPRITE_WIDTH: u32 = 200;
-const SPRITE_HEIGHT: u32 = 291;
+///Width of the cards of the winner, the height is the one of the theme
+const HAND_CARD_W: u32 = 200;
 
 impl Drawable for Game {
     fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
@@ -20,9 +24,10 @@ impl Drawable for Game {
                 if let Some(bg) = gfx.tex_cache.get("TITLE") {
                     gfx.canvas.copy(bg, None, None)?;
                 }
+                let start_delay = self.settings.animation(START_DELAY);
                 gfx.draw_rect(
                     Rect::new(0, 0, WIDTH, HEIGHT), 
-                    Color::RGBA(0, 0, 0, (255.0 * (START_DELAY - self.delay).as_secs_f32()/START_DELAY.as_secs_f32()) as u8)
+                    Color::RGBA(0, 0, 0, (255.0 * start_delay.saturating_sub(self.delay).as_secs_f32()/start_delay.as_secs_f32()) as u8)
                 )?;
             },
             GamePhase::Pause => {
@@ -33,41 +38,62 @@ impl Drawable for Game {
                     Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2),
                     true,
                 )?;
+                let keys = self.settings.keys;
+                let hint = if self.session.is_none() {
+                    format!(
+                        "{}: save the hand    {}: load the saved hand    {}: profile    {}: menu",
+                        keys.save.0.name(),
+                        keys.load.0.name(),
+                        keys.profile.0.name(),
+                        keys.menu.0.name()
+                    )
+                } else {
+                    format!("{}: menu", keys.menu.0.name())
+                };
+                gfx.draw_string(
+                    &hint,
+                    DEFAULT_FONT.derive_size(36),
+                    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 120),
+                    true,
+                )?;
+                if let Some(notice) = &self.notice {
+                    gfx.draw_string(
+                        notice,
+                        DEFAULT_FONT.derive_size(36),
+                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 180),
+                        true,
+                    )?;
+                }
             },
-            GamePhase::Ended(rank, i, pot) => {
+            GamePhase::Ended(_, i, _) => {
                 gfx.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), Color::RGBA(0, 0, 0, 200))?;
 
                 if let Some(players) = &self.players {
-                    let big_color; let big_txt; let small_txt;
-                    if i != self.myself {
-                        big_color = Color::RED;
-                        big_txt = "GAME OVER".to_string();
-                        small_txt = format!("Player \"{}\" won {}€", players[i].name, pot);
-                    } else {
-                        big_color = Color::GREEN;
-                        big_txt = "YOU WON".to_string();
-                        small_txt = format!("You have won {}€", pot);
+                    if !self.review.is_open() {
+                        let (big_color, big_txt) = if i != self.myself {
+                            (Color::RED, "GAME OVER")
+                        } else {
+                            (Color::GREEN, "YOU WON")
+                        };
+                        gfx.draw_string(
+                            big_txt,
+                            DEFAULT_FONT.derive_size(128).derive_color(big_color),
+                            Point::new(WIDTH as i32 / 2, 90),
+                            true,
+                        )?;
+
+                        //The table server knows the stacks after the split pots
+                        let end_stacks = self.hand_stacks.clone().unwrap_or_else(|| {
+                            players
+                                .iter()
+                                .enumerate()
+                                .map(|(k, p)| p.cash + self.summary.won(k))
+                                .collect()
+                        });
+                        draw_results(gfx, players, &self.summary, &self.summary.net(&end_stacks))?;
                     }
 
-                    gfx.draw_string(
-                        &big_txt,
-                        DEFAULT_FONT.derive_size(128).derive_color(big_color),
-                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 - 100),
-                        true,
-                    )?;
-                    gfx.draw_string(
-                        &small_txt,
-                        DEFAULT_FONT.derive_size(48),
-                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2),
-                        true,
-                    )?;
-                    gfx.draw_string(
-                        &format!("Rank: {:?}", rank),
-                        DEFAULT_FONT.derive_size(48),
-                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 70),
-                        true,
-                    )?;
-                    draw_hand(gfx, players, i, Point::new(WIDTH as i32/2, HEIGHT as i32/2 + 280))?;
+                    self.review.draw(gfx)?;
                 }
             }
             GamePhase::Showdown(i) => {
@@ -80,7 +106,77 @@ impl Drawable for Game {
                         true,
                     )?;
 
-                    draw_hand(gfx, players, i, Point::new(WIDTH as i32/2, HEIGHT as i32/2 -(SPRITE_HEIGHT as i32)/2))?;
+                    let (_, h) = gfx.card_sheet.size(HAND_CARD_W);
+                    draw_hand(gfx, players, i, Point::new(WIDTH as i32/2, HEIGHT as i32/2 -(h as i32)/2))?;
+                }
+            }
+            GamePhase::Connecting => {
+                //Before joining the lobby is the title screen
+                let txt = if self.players.is_none() {
+                    if let Some(bg) = gfx.tex_cache.get("TITLE") {
+                        gfx.canvas.copy(bg, None, None)?;
+                    }
+                    "Waiting for the table..."
+                } else {
+                    "Connection lost, joining again..."
+                };
+
+                gfx.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), Color::RGBA(0, 0, 0, 200))?;
+                gfx.draw_string(
+                    txt,
+                    DEFAULT_FONT.derive_size(48),
+                    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2),
+                    true,
+                )?;
+            }
+            GamePhase::Closed => {
+                gfx.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), Color::RGBA(0, 0, 0, 200))?;
+                gfx.draw_string(
+                    "THE TABLE IS CLOSED",
+                    DEFAULT_FONT.derive_size(128),
+                    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2),
+                    true,
+                )?;
+                gfx.draw_string(
+                    &format!("{}: menu", self.settings.keys.menu.0.name()),
+                    DEFAULT_FONT.derive_size(36),
+                    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 120),
+                    true,
+                )?;
+            }
+            GamePhase::Profile => {
+                gfx.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), Color::RGBA(0, 0, 0, 200))?;
+
+                if let Some(profile) = &self.profile {
+                    gfx.draw_string(
+                        &profile.name,
+                        DEFAULT_FONT.derive_size(128),
+                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 - 250),
+                        true,
+                    )?;
+
+                    let lines = [
+                        format!("Bankroll: {}€", profile.bankroll),
+                        format!("Hands played: {}", profile.hands),
+                        format!("Win rate: {:.1}%", 100.0 * profile.win_rate()),
+                        format!("Biggest pot: {}€", profile.biggest_pot),
+                        format!("Net: {}€", profile.net),
+                    ];
+                    for (k, line) in lines.iter().enumerate() {
+                        gfx.draw_string(
+                            line,
+                            DEFAULT_FONT.derive_size(48),
+                            Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 - 100 + 70 * k as i32),
+                            true,
+                        )?;
+                    }
+
+                    gfx.draw_string(
+                        &format!("{}: back", self.settings.keys.profile.0.name()),
+                        DEFAULT_FONT.derive_size(36),
+                        Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 320),
+                        true,
+                    )?;
                 }
             }
             GamePhase::Playing => (),
@@ -91,48 +187,86 @@ impl Drawable for Game {
     }
 }
 
+///The pots and a row for every player with the shown cards, the best five of them highlighted,
+///and how much the player won or lost
+fn draw_results(
+    gfx: &mut SDL2Graphics<'_>,
+    players: &[PlayerState],
+    summary: &HandSummary,
+    net: &[i32],
+) -> Result<(), String> {
+    const ROW_H: i32 = 74;
+    const CARD_W: u32 = 46;
+    let card_size = gfx.card_sheet.size(CARD_W);
+    let font = DEFAULT_FONT.derive_size(36);
+
+    let pots = summary
+        .pots
+        .iter()
+        .map(|&(i, pot)| format!("{pot}€ to {}", players[i].name))
+        .join(", ");
+    gfx.draw_string(&format!("Pots: {pots}"), font, Point::new(WIDTH as i32 / 2, 190), true)?;
+
+    for (k, p) in players.iter().enumerate() {
+        let row = Rect::new(200, 225 + k as i32 * ROW_H, WIDTH - 400, ROW_H as u32 - 6);
+        let color = if summary.won(k) > 0 {
+            Color::RGBA(255, 215, 0, 60)
+        } else {
+            Color::RGBA(255, 255, 255, 20)
+        };
+        gfx.draw_rect(row, color)?;
+
+        let y = row.center().y;
+        gfx.draw_string(&p.name, font, Point::new(row.x + 20, y - 18), false)?;
+
+        match summary.shown.iter().find(|s| s.i == k) {
+            Some(shown) => {
+                let cards = [shown.hand.0, shown.hand.1]
+                    .into_iter()
+                    .chain(summary.community.iter().copied());
+                for (n, card) in cards.enumerate() {
+                    //The hole cards are apart from the community cards
+                    let x = 600 + n as i32 * (CARD_W as i32 + 6) + if n >= 2 { 20 } else { 0 };
+                    let opacity = if shown.best.contains(&card) { 1.0 } else { 0.35 };
+                    let pose = Pose::at(Point::new(x, y)).with_opacity(opacity);
+                    draw_card(gfx, Some(card), &pose, card_size, 0.0)?;
+                }
+                gfx.draw_string(shown.rank.name(), font, Point::new(1020, y - 18), false)?;
+            }
+            None => {
+                let status = if p.folded { "folded" } else { "mucked" };
+                gfx.draw_string(status, font, Point::new(580, y - 18), false)?;
+            }
+        }
+
+        if let Some(change) = net.get(k) {
+            gfx.draw_string(&format!("{change:+}€"), font, Point::new(row.right() - 160, y - 18), false)?;
+        }
+    }
+
+    Ok(())
+}
+
 fn draw_hand(gfx: &mut SDL2Graphics<'_>, players: &[PlayerState], i: usize, center: Point) -> Result<(), String> {
+    let [first, second] = [
+        players[i].hand.map(|hand| hand.0),
+        players[i].hand.map(|hand| hand.1),
+    ].map(|card| gfx.card_sheet.rect(card, gfx.card_back));
+    let (w, h) = gfx.card_sheet.size(HAND_CARD_W);
+
     Ok(if let Some(tex) = gfx.tex_cache.get("CARD") {            
-        let p = center.offset(-5, -(SPRITE_HEIGHT as i32)/2);
+        let p = center.offset(-5, -(h as i32)/2);
         gfx.canvas.copy(
             tex,
-            rect_card_spritesheet(players[i].hand.map(|hand| hand.0)),
-            Rect::new(p.x, p.y, SPRITE_WIDTH, SPRITE_HEIGHT),
+            first,
+            Rect::new(p.x, p.y, w, h),
         )?;
             
-        let p = p.offset(-(SPRITE_WIDTH as i32) - 10, 0);
+        let p = p.offset(-(w as i32) - 10, 0);
         gfx.canvas.copy(
             tex,
-            rect_card_spritesheet(players[i].hand.map(|hand| hand.1)),
-            Rect::new(p.x, p.y, SPRITE_WIDTH, SPRITE_HEIGHT),
+            second,
+            Rect::new(p.x, p.y, w, h),
         )?;
     })
 }
-
-pub fn rect_card_spritesheet(card: Option<Card>) -> Rect {
-    match card {
-        Some(card) => {
-            let x_offset = {
-                if card.value == Value::Ace {
-                    0
-                } else {
-                    card.value as i32 + 1
-                }
-            };
-            let y_offset = card.suit as i32;
-
-            Rect::new(
-                (x_offset % 13) * SPRITE_WIDTH as i32,
-                y_offset * SPRITE_HEIGHT as i32,
-                SPRITE_WIDTH,
-                SPRITE_HEIGHT,
-            )
-        }
-        None => Rect::new(
-            2 * SPRITE_WIDTH as i32,
-            4 * SPRITE_HEIGHT as i32,
-            SPRITE_WIDTH,
-            SPRITE_HEIGHT,
-        ),
-    }
-}
\ No newline at end of file
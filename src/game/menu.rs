@@ -0,0 +1,849 @@
+use std::path::Path;
+
+use log::warn;
+use sdl2::{
+    event::Event,
+    mouse::MouseButton,
+    pixels::Color,
+    rect::{Point, Rect},
+};
+
+use crate::graphic::{
+    avatar::{self, draw_avatar, Avatar, AVATARS_DIR},
+    button::{Button, ButtonColor, ButtonState},
+    dropdown::Dropdown,
+    font::DEFAULT_FONT,
+    slider::Slider,
+    text_input::TextInput,
+    ui::MAX_PLAYERS,
+    ui_component::{Drawable, EventReceiver},
+    Display, SDL2Graphics, WindowMode, HEIGHT, RESOLUTIONS, WIDTH,
+};
+use crate::settings::{
+    Key, KeyBindings, Settings, MAX_ANIMATION_SPEED, MIN_ANIMATION_SPEED, SETTINGS_PATH,
+};
+use crate::theme::{Theme, THEMES_DIR};
+
+use super::profile::{Profile, PROFILE_PATH};
+use super::save::SAVE_PATH;
+use super::setup::{Difficulty, GameSetup, Seat, Variant, BLINDS, MAX_OPPONENTS, TIME_LIMITS};
+
+pub const MENU_COLOR: ButtonColor = ButtonColor {
+    color: Color::RGB(42, 92, 189),
+    hover_color: Color::RGB(28, 61, 128),
+    pressed_color: Color::RGB(17, 37, 77),
+    inactive_color: Color::RGB(102, 110, 128),
+};
+
+const INPUT_COLOR: Color = Color::RGB(40, 40, 40);
+const INPUT_FOCUS_COLOR: Color = Color::RGB(80, 80, 80);
+
+///Where the widgets of every column start, the labels are on their left
+const CENTER_X: i32 = WIDTH as i32 / 2 - 50;
+const LEFT_X: i32 = 480;
+const RIGHT_X: i32 = 1380;
+const LABEL_W: i32 = 400;
+const WIDGET_W: u32 = 480;
+const ROW_H: u32 = 60;
+///Where the seats of the setup screen start, the picture on the left of the name
+const SEATS_X: i32 = 1430;
+///The rows of the right column of the settings: the keys, the effects, the four colours and the
+///card back
+const KEY_ROWS: i32 = KeyBindings::NAMES.len() as i32 + 3;
+///The table shown in the join screen, the one of `cargo run --bin server`
+const DEFAULT_TABLE: &str = "127.0.0.1:7878";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MenuScreen {
+    #[default]
+    Main,
+    Setup,
+    ///Asks for the table server to play in
+    Join,
+    Settings,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MenuChoice {
+    NewGame(GameSetup),
+    ///Continues the saved hand
+    Continue,
+    ///Sits at the table of the server with the name
+    Connect { addr: String, name: String },
+    ///The settings were changed and have to be applied
+    Settings(Settings),
+    Quit,
+}
+
+///The screens shown before the game starts
+pub struct Menu {
+    screen: MenuScreen,
+    profile: Profile,
+    settings: Settings,
+    setup: GameSetup,
+    ///The resolutions of the settings, with the one of the window if it isn't in the list
+    resolutions: Vec<(u32, u32)>,
+    ///The themes found in the assets
+    themes: Vec<Theme>,
+    has_save: bool,
+    notice: Option<String>,
+
+    new_game_btn: Button,
+    join_btn: Button,
+    continue_btn: Button,
+    settings_btn: Button,
+    quit_btn: Button,
+
+    opponents: Slider,
+    blind: Slider,
+    stack: TextInput,
+    variant: Dropdown,
+    difficulty: Dropdown,
+    ///The pictures that can be chosen for the seats
+    avatar_images: Vec<String>,
+    ///The name of every seat, the local player first
+    seat_names: Vec<TextInput>,
+    ///The picture of every seat, the face is generated without one
+    seat_avatars: Vec<Option<String>>,
+    start_btn: Button,
+    back_btn: Button,
+
+    address: TextInput,
+    join_name: TextInput,
+    connect_btn: Button,
+
+    name: TextInput,
+    time_limit: Dropdown,
+    window_mode: Dropdown,
+    resolution: Dropdown,
+    animation_speed: Slider,
+    music_volume: Slider,
+    effects_volume: Slider,
+    theme: Dropdown,
+    four_color_deck: Button,
+    card_back: Button,
+    ///A button for every key binding, in the order of [`KeyBindings::NAMES`]
+    key_btns: Vec<Button>,
+    ///The key binding that changes with the next key pressed
+    rebinding: Option<usize>,
+}
+
+impl EventReceiver<Option<MenuChoice>> for Menu {
+    fn handle_event(&mut self, event: &Event) -> Option<MenuChoice> {
+        match self.screen {
+            MenuScreen::Main => self.handle_main(event),
+            MenuScreen::Setup => self.handle_setup(event),
+            MenuScreen::Join => self.handle_join(event),
+            MenuScreen::Settings => self.handle_settings(event),
+        }
+    }
+}
+
+impl Drawable for Menu {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        gfx.clear()?;
+        if let Some(bg) = gfx.tex_cache.get("TITLE") {
+            gfx.canvas.copy(bg, None, None)?;
+        }
+
+        match self.screen {
+            MenuScreen::Main => {
+                self.new_game_btn.draw(gfx)?;
+                self.join_btn.draw(gfx)?;
+                self.continue_btn.draw(gfx)?;
+                self.settings_btn.draw(gfx)?;
+                self.quit_btn.draw(gfx)?;
+            }
+            MenuScreen::Setup => {
+                draw_title(gfx, "NEW GAME")?;
+
+                draw_label(gfx, CENTER_X, 0, &format!("Opponents: {}", self.setup.opponents))?;
+                self.opponents.draw(gfx)?;
+                draw_label(gfx, CENTER_X, 1, &format!("Blinds: {}€", self.setup.blind))?;
+                self.blind.draw(gfx)?;
+                draw_label(gfx, CENTER_X, 2, "Starting stacks")?;
+                self.stack.draw(gfx)?;
+                draw_label(gfx, CENTER_X, 3, "Variant")?;
+                draw_label(gfx, CENTER_X, 4, "Bots")?;
+                self.draw_seats(gfx)?;
+                self.start_btn.draw(gfx)?;
+                self.back_btn.draw(gfx)?;
+
+                //The open list goes over the rest of the widgets
+                let mut dropdowns = [&self.difficulty, &self.variant];
+                dropdowns.sort_by_key(|d| d.is_open());
+                for d in dropdowns {
+                    d.draw(gfx)?;
+                }
+            }
+            MenuScreen::Join => {
+                draw_title(gfx, "JOIN TABLE")?;
+
+                draw_label(gfx, CENTER_X, 0, "Address")?;
+                self.address.draw(gfx)?;
+                draw_label(gfx, CENTER_X, 1, "Name")?;
+                self.join_name.draw(gfx)?;
+                self.connect_btn.draw(gfx)?;
+                self.back_btn.draw(gfx)?;
+            }
+            MenuScreen::Settings => {
+                draw_title(gfx, "SETTINGS")?;
+
+                draw_label(gfx, LEFT_X, 0, "Name")?;
+                self.name.draw(gfx)?;
+                draw_label(gfx, LEFT_X, 1, "Time to decide")?;
+                draw_label(gfx, LEFT_X, 2, "Window")?;
+                draw_label(gfx, LEFT_X, 3, "Resolution")?;
+                draw_label(
+                    gfx,
+                    LEFT_X,
+                    4,
+                    &format!("Animations: {:.1}x", self.settings.animation_speed),
+                )?;
+                self.animation_speed.draw(gfx)?;
+                draw_label(
+                    gfx,
+                    LEFT_X,
+                    5,
+                    &format!("Music: {:.0}%", 100.0 * self.settings.music_volume),
+                )?;
+                self.music_volume.draw(gfx)?;
+                draw_label(gfx, LEFT_X, 6, "Theme")?;
+
+                for (row, (name, btn)) in KeyBindings::NAMES.iter().zip(&self.key_btns).enumerate() {
+                    draw_label_at(gfx, key_bounds(row as i32), name)?;
+                    btn.draw(gfx)?;
+                }
+                draw_label_at(
+                    gfx,
+                    key_bounds(KeyBindings::NAMES.len() as i32),
+                    &format!("Effects: {:.0}%", 100.0 * self.settings.effects_volume),
+                )?;
+                self.effects_volume.draw(gfx)?;
+                draw_label_at(gfx, key_bounds(KeyBindings::NAMES.len() as i32 + 1), "Four colours")?;
+                self.four_color_deck.draw(gfx)?;
+                draw_label_at(gfx, key_bounds(KeyBindings::NAMES.len() as i32 + 2), "Card back")?;
+                self.card_back.draw(gfx)?;
+                self.back_btn.draw(gfx)?;
+
+                let mut dropdowns = [
+                    &self.theme,
+                    &self.resolution,
+                    &self.window_mode,
+                    &self.time_limit,
+                ];
+                dropdowns.sort_by_key(|d| d.is_open());
+                for d in dropdowns {
+                    d.draw(gfx)?;
+                }
+            }
+        }
+
+        if let Some(notice) = &self.notice {
+            gfx.draw_string(
+                notice,
+                DEFAULT_FONT.derive_size(36),
+                Point::new(WIDTH as i32 / 2, HEIGHT as i32 - 60),
+                true,
+            )?;
+        }
+
+        gfx.show();
+        Ok(())
+    }
+}
+
+impl Menu {
+    pub fn new(profile: Profile, settings: Settings) -> Self {
+        let setup = GameSetup::from_settings(&settings);
+        let display = settings.display;
+
+        let menu_button = |text: &str, bounds: Rect| {
+            let mut btn = Button::new(text.to_string(), bounds, MENU_COLOR);
+            btn.set_font(DEFAULT_FONT.derive_size(36));
+            btn
+        };
+        let main_button = |text: &str, k: i32| {
+            menu_button(
+                text,
+                Rect::new(WIDTH as i32 / 2 - 250, 350 + 130 * k, 500, 110),
+            )
+        };
+        let dropdown = |options: Vec<String>, x: i32, row: i32, selected: usize| {
+            let mut d = Dropdown::new(options, widget_bounds(x, row), MENU_COLOR);
+            d.set_font(DEFAULT_FONT.derive_size(36));
+            d.select(selected);
+            d
+        };
+        let text_input = |text: String, x: i32, row: i32| {
+            let mut input = TextInput::new(
+                text,
+                widget_bounds(x, row),
+                INPUT_COLOR,
+                INPUT_FOCUS_COLOR,
+            );
+            input.set_font(DEFAULT_FONT.derive_size(36));
+            input
+        };
+        let slider_at = |bounds: Rect, value: f32| {
+            let mut s = Slider::new(
+                Rect::from_center(bounds.center(), bounds.width(), 30),
+                Color::BLUE,
+                Color::GRAY,
+                Color::BLACK,
+            );
+            s.set_value(value);
+            s
+        };
+        let slider = |x: i32, row: i32, value: f32| slider_at(widget_bounds(x, row), value);
+
+        let blind = BLINDS.iter().position(|&b| b == setup.blind).unwrap_or(0);
+        let variant = Variant::ALL.iter().position(|&v| v == setup.variant);
+        let difficulty = Difficulty::ALL.iter().position(|&d| d == setup.difficulty);
+        let time_limit = TIME_LIMITS.iter().position(|&t| t == settings.time_limit);
+
+        let mut resolutions = RESOLUTIONS.to_vec();
+        if !resolutions.contains(&(display.width, display.height)) {
+            resolutions.push((display.width, display.height));
+        }
+        let window_mode = WindowMode::ALL.iter().position(|&m| m == display.mode);
+        let resolution = resolutions
+            .iter()
+            .position(|&r| r == (display.width, display.height));
+
+        //The first seat is the local player
+        let seats = std::iter::once(Seat {
+            name: profile.name.clone(),
+            avatar: profile.avatar.clone(),
+        })
+        .chain((1..MAX_PLAYERS).map(|k| match settings.seats.get(k - 1) {
+            Some(seat) if !seat.name.trim().is_empty() => seat.clone(),
+            seat => Seat {
+                name: format!("Player{}", k + 1),
+                avatar: seat.and_then(|s| s.avatar.clone()),
+            },
+        }))
+        .collect::<Vec<_>>();
+        let mut avatar_images = avatar::images(AVATARS_DIR);
+        for path in seats.iter().filter_map(|s| s.avatar.clone()) {
+            if !avatar_images.contains(&path) {
+                avatar_images.push(path);
+            }
+        }
+        let seat_names = seats
+            .iter()
+            .enumerate()
+            .map(|(row, seat)| {
+                let mut input = TextInput::new(
+                    seat.name.clone(),
+                    seat_name_bounds(row as i32),
+                    INPUT_COLOR,
+                    INPUT_FOCUS_COLOR,
+                );
+                input.set_font(DEFAULT_FONT.derive_size(36));
+                input
+            })
+            .collect();
+
+        let themes = Theme::all(THEMES_DIR);
+        let theme = themes.iter().position(|t| t.name == settings.theme);
+
+        let speed = (settings.animation_speed - MIN_ANIMATION_SPEED)
+            / (MAX_ANIMATION_SPEED - MIN_ANIMATION_SPEED);
+
+        let key_btns = settings
+            .keys
+            .keys()
+            .iter()
+            .enumerate()
+            .map(|(row, key)| menu_button(&key.0.name(), key_bounds(row as i32)))
+            .collect();
+
+        let mut menu = Menu {
+            screen: MenuScreen::default(),
+            has_save: Path::new(SAVE_PATH).exists(),
+            notice: None,
+
+            new_game_btn: main_button("NEW GAME", 0),
+            join_btn: main_button("JOIN TABLE", 1),
+            continue_btn: main_button("CONTINUE", 2),
+            settings_btn: main_button("SETTINGS", 3),
+            quit_btn: main_button("QUIT", 4),
+
+            opponents: slider(
+                CENTER_X,
+                0,
+                (setup.opponents - 1) as f32 / (MAX_OPPONENTS - 1) as f32,
+            ),
+            blind: slider(CENTER_X, 1, blind as f32 / (BLINDS.len() - 1) as f32),
+            stack: text_input(setup.stack.to_string(), CENTER_X, 2).numeric(),
+            variant: dropdown(
+                Variant::ALL.iter().map(|v| v.name().to_string()).collect(),
+                CENTER_X,
+                3,
+                variant.unwrap_or(0),
+            ),
+            difficulty: dropdown(
+                Difficulty::ALL.iter().map(|d| d.name().to_string()).collect(),
+                CENTER_X,
+                4,
+                difficulty.unwrap_or(0),
+            ),
+            avatar_images,
+            seat_names,
+            seat_avatars: seats.into_iter().map(|s| s.avatar).collect(),
+            start_btn: menu_button(
+                "START",
+                Rect::new(WIDTH as i32 / 2 + 50, 880, 400, 110),
+            ),
+            back_btn: menu_button(
+                "BACK",
+                Rect::new(WIDTH as i32 / 2 - 450, 880, 400, 110),
+            ),
+
+            address: text_input(DEFAULT_TABLE.to_string(), CENTER_X, 0),
+            join_name: text_input(profile.name.clone(), CENTER_X, 1),
+            connect_btn: menu_button(
+                "JOIN",
+                Rect::new(WIDTH as i32 / 2 + 50, 880, 400, 110),
+            ),
+
+            name: text_input(profile.name.clone(), LEFT_X, 0),
+            time_limit: dropdown(
+                TIME_LIMITS
+                    .iter()
+                    .map(|t| match t {
+                        Some(secs) => format!("{secs} seconds"),
+                        None => "No limit".to_string(),
+                    })
+                    .collect(),
+                LEFT_X,
+                1,
+                time_limit.unwrap_or(0),
+            ),
+            window_mode: dropdown(
+                WindowMode::ALL.iter().map(|m| m.name().to_string()).collect(),
+                LEFT_X,
+                2,
+                window_mode.unwrap_or(0),
+            ),
+            resolution: dropdown(
+                resolutions.iter().map(|(w, h)| format!("{w}x{h}")).collect(),
+                LEFT_X,
+                3,
+                resolution.unwrap_or(0),
+            ),
+            animation_speed: slider(LEFT_X, 4, speed),
+            music_volume: slider(LEFT_X, 5, settings.music_volume),
+            effects_volume: slider_at(
+                key_bounds(KeyBindings::NAMES.len() as i32),
+                settings.effects_volume,
+            ),
+            theme: dropdown(
+                themes.iter().map(|t| t.name.clone()).collect(),
+                LEFT_X,
+                6,
+                theme.unwrap_or(0),
+            ),
+            four_color_deck: menu_button(
+                on_off(settings.four_color_deck),
+                key_bounds(KeyBindings::NAMES.len() as i32 + 1),
+            ),
+            card_back: menu_button("", key_bounds(KeyBindings::NAMES.len() as i32 + 2)),
+            key_btns,
+            rebinding: None,
+
+            resolutions,
+            themes,
+            profile,
+            settings,
+            setup,
+        };
+        if !menu.has_save {
+            menu.continue_btn.set_inactive();
+        }
+        menu.update_card_back();
+
+        menu
+    }
+
+    ///The profile with the name chosen in the settings
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    fn open(&mut self, screen: MenuScreen) {
+        self.screen = screen;
+        self.notice = None;
+    }
+
+    fn handle_main(&mut self, event: &Event) -> Option<MenuChoice> {
+        let new_game = self.new_game_btn.handle_event(event) == ButtonState::Pressed;
+        let join = self.join_btn.handle_event(event) == ButtonState::Pressed;
+        let cont = self.continue_btn.handle_event(event) == ButtonState::Pressed;
+        let settings = self.settings_btn.handle_event(event) == ButtonState::Pressed;
+        let quit = self.quit_btn.handle_event(event) == ButtonState::Pressed;
+
+        if !self.has_save {
+            self.continue_btn.set_inactive();
+        }
+
+        if new_game {
+            self.seat_names[0].set_text(self.profile.name.clone());
+            self.open(MenuScreen::Setup);
+        } else if join {
+            self.join_name.set_text(self.profile.name.clone());
+            self.open(MenuScreen::Join);
+        } else if cont && self.has_save {
+            return Some(MenuChoice::Continue);
+        } else if settings {
+            self.open(MenuScreen::Settings);
+        } else if quit {
+            return Some(MenuChoice::Quit);
+        }
+
+        None
+    }
+
+    fn handle_setup(&mut self, event: &Event) -> Option<MenuChoice> {
+        let open = [&mut self.variant, &mut self.difficulty]
+            .into_iter()
+            .find(|d| d.is_open());
+        if let Some(d) = open {
+            d.handle_event(event);
+            self.setup.variant = Variant::ALL[self.variant.selected()];
+            self.setup.difficulty = Difficulty::ALL[self.difficulty.selected()];
+            return None;
+        }
+
+        self.setup.opponents = GameSetup::opponents_from(self.opponents.handle_event(event));
+        self.setup.blind = GameSetup::blind_from(self.blind.handle_event(event));
+        self.stack.handle_event(event);
+        self.setup.variant = Variant::ALL[self.variant.handle_event(event)];
+        self.setup.difficulty = Difficulty::ALL[self.difficulty.handle_event(event)];
+        self.handle_seats(event);
+
+        if self.back_btn.handle_event(event) == ButtonState::Pressed {
+            self.open(MenuScreen::Main);
+        } else if self.start_btn.handle_event(event) == ButtonState::Pressed {
+            match self.stack.text().parse::<i32>() {
+                Ok(stack) if stack >= self.setup.blind => {
+                    self.setup.stack = stack;
+
+                    self.name.set_text(self.seat_names[0].text().to_string());
+                    self.save_profile();
+
+                    //The next games start with the same bots and seats
+                    let seats = self.seats();
+                    if self.settings.difficulty != self.setup.difficulty
+                        || self.settings.seats != seats
+                    {
+                        self.settings.difficulty = self.setup.difficulty;
+                        self.settings.seats = seats;
+                        self.save_settings();
+                    }
+
+                    return Some(MenuChoice::NewGame(self.setup));
+                }
+                _ => {
+                    self.notice = Some(format!(
+                        "The starting stacks must be at least the blind ({}€)",
+                        self.setup.blind
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn handle_join(&mut self, event: &Event) -> Option<MenuChoice> {
+        self.address.handle_event(event);
+        self.join_name.handle_event(event);
+
+        if self.back_btn.handle_event(event) == ButtonState::Pressed {
+            self.open(MenuScreen::Main);
+        } else if self.connect_btn.handle_event(event) == ButtonState::Pressed {
+            let addr = self.address.text().trim().to_string();
+            let name = self.join_name.text().trim().to_string();
+            if addr.is_empty() || name.is_empty() {
+                self.notice = Some("Write the address of the table and a name".to_string());
+            } else {
+                return Some(MenuChoice::Connect { addr, name });
+            }
+        }
+
+        None
+    }
+
+    fn handle_settings(&mut self, event: &Event) -> Option<MenuChoice> {
+        if let Some(i) = self.rebinding {
+            if let Event::KeyDown {
+                keycode: Some(key), ..
+            } = event
+            {
+                //The binding that had the key gets the old one, so no key does two things
+                let swapped = self.settings.keys.rebind(i, Key(*key));
+                let keys = self.settings.keys.keys();
+                for j in std::iter::once(i).chain(swapped) {
+                    self.key_btns[j].set_text(keys[j].0.name());
+                }
+                self.rebinding = None;
+                return Some(MenuChoice::Settings(self.settings.clone()));
+            }
+            return None;
+        }
+
+        let mut four_color_deck = false;
+        let mut next_back = false;
+
+        let mut dropdowns = [
+            &mut self.time_limit,
+            &mut self.window_mode,
+            &mut self.resolution,
+            &mut self.theme,
+        ];
+        let open = dropdowns.iter().position(|d| d.is_open());
+        if let Some(i) = open {
+            dropdowns[i].handle_event(event);
+        } else {
+            for d in dropdowns {
+                d.handle_event(event);
+            }
+            self.name.handle_event(event);
+            self.animation_speed.handle_event(event);
+            self.music_volume.handle_event(event);
+            self.effects_volume.handle_event(event);
+
+            for (i, btn) in self.key_btns.iter_mut().enumerate() {
+                if btn.handle_event(event) == ButtonState::Pressed {
+                    btn.set_text("...".to_string());
+                    self.rebinding = Some(i);
+                }
+            }
+
+            four_color_deck = self.four_color_deck.handle_event(event) == ButtonState::Pressed;
+            next_back = self.card_back.handle_event(event) == ButtonState::Pressed;
+
+            if self.back_btn.handle_event(event) == ButtonState::Pressed {
+                self.save_profile();
+                self.save_settings();
+                self.open(MenuScreen::Main);
+            }
+        }
+
+        //Every theme has its own backs
+        let theme = &self.themes[self.theme.selected()];
+        let backs = theme.cards.backs.len().max(1);
+        let card_back = if theme.name != self.settings.theme {
+            0
+        } else if next_back {
+            (self.settings.card_back + 1) % backs
+        } else {
+            self.settings.card_back
+        };
+
+        let (width, height) = self.resolutions[self.resolution.selected()];
+        let settings = Settings {
+            animation_speed: MIN_ANIMATION_SPEED
+                + self.animation_speed.value() * (MAX_ANIMATION_SPEED - MIN_ANIMATION_SPEED),
+            display: Display {
+                mode: WindowMode::ALL[self.window_mode.selected()],
+                width,
+                height,
+            },
+            music_volume: self.music_volume.value(),
+            effects_volume: self.effects_volume.value(),
+            theme: theme.name.clone(),
+            four_color_deck: self.settings.four_color_deck != four_color_deck,
+            card_back,
+            time_limit: TIME_LIMITS[self.time_limit.selected()],
+            ..self.settings.clone()
+        };
+        self.setup.time_limit = GameSetup::time_limit_from(settings.time_limit);
+
+        if settings != self.settings {
+            self.settings = settings;
+            self.four_color_deck.set_text(on_off(self.settings.four_color_deck).to_string());
+            self.update_card_back();
+            return Some(MenuChoice::Settings(self.settings.clone()));
+        }
+
+        None
+    }
+
+    ///Shows the chosen back of the ones of the theme
+    fn update_card_back(&mut self) {
+        let backs = self
+            .themes
+            .iter()
+            .find(|t| t.name == self.settings.theme)
+            .map_or(1, |t| t.cards.backs.len().max(1));
+        self.card_back
+            .set_text(format!("{}/{backs}", self.settings.card_back + 1));
+    }
+
+    ///The names are typed and a click on the picture shows the next one, the last one is the
+    ///generated face
+    fn handle_seats(&mut self, event: &Event) {
+        let seats = self.setup.opponents + 1;
+        for input in &mut self.seat_names[..seats] {
+            input.handle_event(event);
+        }
+
+        if let Event::MouseButtonDown {
+            mouse_btn: MouseButton::Left,
+            x,
+            y,
+            ..
+        } = event
+        {
+            let row = (0..seats)
+                .find(|&row| seat_avatar_bounds(row as i32).contains_point((*x, *y)));
+            if let Some(row) = row {
+                let avatar = &mut self.seat_avatars[row];
+                let next = match avatar.as_deref() {
+                    Some(path) => self
+                        .avatar_images
+                        .iter()
+                        .position(|p| p == path)
+                        .map_or(0, |i| i + 1),
+                    None => 0,
+                };
+                *avatar = self.avatar_images.get(next).cloned();
+            }
+        }
+    }
+
+    ///The opponent seats to keep in the settings
+    fn seats(&self) -> Vec<Seat> {
+        self.seat_names
+            .iter()
+            .zip(&self.seat_avatars)
+            .skip(1)
+            .map(|(name, avatar)| Seat {
+                name: name.text().trim().to_string(),
+                avatar: avatar.clone(),
+            })
+            .collect()
+    }
+
+    fn draw_seats(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        gfx.draw_string(
+            "Players",
+            DEFAULT_FONT.derive_size(36),
+            Point::new(SEATS_X, 180),
+            false,
+        )?;
+
+        for row in 0..=self.setup.opponents {
+            let bot = (row > 0).then_some(self.setup.difficulty);
+            let name = self.seat_names[row].text();
+            let avatar = Avatar::or_generated(self.seat_avatars[row].as_deref(), bot, name);
+            draw_avatar(gfx, &avatar, seat_avatar_bounds(row as i32))?;
+            self.seat_names[row].draw(gfx)?;
+        }
+
+        Ok(())
+    }
+
+    fn save_settings(&self) {
+        if let Err(e) = self.settings.save(SETTINGS_PATH) {
+            warn!("Couldn't save the settings: {e}");
+        }
+    }
+
+    ///Saves the name of the settings and the picture of the first seat
+    fn save_profile(&mut self) {
+        let name = self.name.text().trim();
+        let name = if name.is_empty() {
+            self.profile.name.clone()
+        } else {
+            name.to_string()
+        };
+        let avatar = self.seat_avatars[0].clone();
+        if name == self.profile.name && avatar == self.profile.avatar {
+            return;
+        }
+
+        self.profile.name = name;
+        self.profile.avatar = avatar;
+        if let Err(e) = self.profile.save(PROFILE_PATH) {
+            warn!("Couldn't save the profile: {e}");
+        }
+    }
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
+fn widget_bounds(x: i32, row: i32) -> Rect {
+    Rect::new(x, 230 + 90 * row, WIDGET_W, ROW_H)
+}
+
+///The keys are closer together so all of them fit in the right column
+fn key_bounds(row: i32) -> Rect {
+    Rect::new(RIGHT_X, 230 + 55 * row, WIDGET_W, 46)
+}
+
+fn seat_avatar_bounds(row: i32) -> Rect {
+    Rect::new(SEATS_X, 230 + 65 * row, 50, 50)
+}
+
+fn seat_name_bounds(row: i32) -> Rect {
+    Rect::new(SEATS_X + 60, 230 + 65 * row, 390, 50)
+}
+
+fn draw_title(gfx: &mut SDL2Graphics, title: &str) -> Result<(), String> {
+    gfx.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), Color::RGBA(0, 0, 0, 200))?;
+    gfx.draw_string(
+        title,
+        DEFAULT_FONT.derive_size(72),
+        Point::new(WIDTH as i32 / 2, 130),
+        true,
+    )
+}
+
+///Draws the label of the widget in the column that starts in x
+fn draw_label(gfx: &mut SDL2Graphics, x: i32, row: i32, label: &str) -> Result<(), String> {
+    draw_label_at(gfx, widget_bounds(x, row), label)
+}
+
+fn draw_label_at(gfx: &mut SDL2Graphics, bounds: Rect, label: &str) -> Result<(), String> {
+    gfx.draw_string(
+        label,
+        DEFAULT_FONT.derive_size(36),
+        Point::new(bounds.x - LABEL_W, bounds.center().y - 18),
+        false,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_rows_fit_in_the_screen() {
+        let screen = Rect::new(0, 0, WIDTH, HEIGHT);
+
+        for row in 0..KEY_ROWS {
+            let bounds = key_bounds(row);
+            assert!(screen.contains_rect(bounds));
+            assert!(bounds.x - LABEL_W >= 0);
+            //The notices are drawn at the bottom
+            assert!(bounds.bottom() <= HEIGHT as i32 - 80);
+        }
+        for row in 0..7 {
+            assert!(screen.contains_rect(widget_bounds(LEFT_X, row)));
+        }
+    }
+}
@@ -1,8 +1,14 @@
-use crate::core::{player::PlayerHand, rank::Rank};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{player::PlayerHand, rank::Rank, stats::SeatStats};
+use crate::graphic::avatar::Avatar;
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct PlayerState {
     pub name: String,
+    pub avatar: Avatar,
 
     pub hand: Option<PlayerHand>,
     pub rank: Option<Rank>,
@@ -14,9 +20,15 @@ pub struct PlayerState {
     pub can_raise: bool,
     pub folded: bool,
     pub all_in: bool,
+
+    pub stats: SeatStats,
+
+    ///The time to decide, counting down while it's the turn of the player
+    pub time_left: Option<Duration>,
+    pub time_total: Duration,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum PlayerAction {
     Fold,
     Call,
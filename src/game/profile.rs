@@ -0,0 +1,127 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+///File where the profile of the local player is kept
+pub const PROFILE_PATH: &str = "profile.json";
+///Money of a new profile
+pub const START_BANKROLL: i32 = 100000;
+
+///The local player, kept between sessions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    ///The picture of the seat, the face is generated without it
+    #[serde(default)]
+    pub avatar: Option<String>,
+    pub bankroll: i32,
+
+    pub hands: u32,
+    pub hands_won: u32,
+    pub biggest_pot: i32,
+    ///Money won minus money lost in every hand
+    pub net: i64,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile {
+            name: "Me".to_string(),
+            avatar: None,
+            bankroll: START_BANKROLL,
+            hands: 0,
+            hands_won: 0,
+            biggest_pot: 0,
+            net: 0,
+        }
+    }
+}
+
+impl Profile {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    ///A new profile is created if there isn't one or it can't be read
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Self {
+        match Profile::load(&path) {
+            Ok(profile) => profile,
+            Err(e) => {
+                if e.kind() != io::ErrorKind::NotFound {
+                    warn!("Couldn't read the profile, a new one is created: {e}");
+                }
+                Profile::default()
+            }
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    ///Updates the bankroll with the money after the hand, the pot is given if the player won it
+    pub fn record_hand(&mut self, bankroll: i32, won_pot: Option<i32>) {
+        self.hands += 1;
+        self.net += (bankroll - self.bankroll) as i64;
+        self.bankroll = bankroll;
+
+        if let Some(pot) = won_pot {
+            self.hands_won += 1;
+            self.biggest_pot = self.biggest_pot.max(pot);
+        }
+    }
+
+    ///Share of the hands won, from 0 to 1
+    pub fn win_rate(&self) -> f64 {
+        if self.hands == 0 {
+            0.0
+        } else {
+            self.hands_won as f64 / self.hands as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_hands() {
+        let mut profile = Profile::default();
+
+        profile.record_hand(START_BANKROLL + 500, Some(800));
+        profile.record_hand(START_BANKROLL + 400, None);
+        profile.record_hand(START_BANKROLL + 600, Some(300));
+
+        assert_eq!(profile.hands, 3);
+        assert_eq!(profile.bankroll, START_BANKROLL + 600);
+        assert_eq!(profile.net, 600);
+        assert_eq!(profile.biggest_pot, 800);
+        assert!((profile.win_rate() - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn save_and_load() -> io::Result<()> {
+        let mut profile = Profile {
+            name: "Alice".to_string(),
+            ..Default::default()
+        };
+        profile.record_hand(10, None);
+
+        let path = std::env::temp_dir().join(format!("poker-profile-{}.json", std::process::id()));
+        profile.save(&path)?;
+        let loaded = Profile::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded, profile);
+        assert_eq!(Profile::load_or_default(&path), Profile::default());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::snapshot::Snapshot;
+use crate::graphic::avatar::Avatar;
+
+use super::setup::GameSetup;
+
+///File where the pause menu saves the game
+pub const SAVE_PATH: &str = "save.json";
+
+///A local game stopped in the middle of a hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub names: Vec<String>,
+    ///The saves without pictures have generated faces
+    #[serde(default)]
+    pub avatars: Vec<Avatar>,
+    pub myself: usize,
+    pub snapshot: Snapshot,
+    ///The blinds, the bots and the clock of the next hands
+    #[serde(default)]
+    pub setup: GameSetup,
+}
+
+impl SavedGame {
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(file, self)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    ///Checks that every seat of the hand is in the table, the file can be edited by hand
+    pub fn check(&self) -> io::Result<()> {
+        let n = self.names.len();
+        let state = &self.snapshot.state;
+        let invalid = |msg: &str| Err(io::Error::new(io::ErrorKind::InvalidData, msg.to_string()));
+
+        if n < 2 || self.myself >= n {
+            return invalid("The saved table has no seat for the player");
+        }
+        if [
+            self.snapshot.hands.len(),
+            state.players_money.len(),
+            state.players_bet.len(),
+        ]
+        .iter()
+        .any(|&len| len != n)
+        {
+            return invalid("The saved hand doesn't match the players of the table");
+        }
+        let seats = [
+            &self.snapshot.pending,
+            &state.folded_players,
+            &state.players_all_in,
+            &state.active_players,
+        ];
+        if seats.iter().any(|seats| seats.iter().any(|&i| i >= n)) {
+            return invalid("The saved hand has seats that aren't in the table");
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::state::{GameState, Round};
+
+    use super::*;
+
+    fn saved(n: usize) -> SavedGame {
+        let card = crate::core::card::Card::new(
+            crate::core::card::Suit::Club,
+            crate::core::card::Value::Ace,
+        );
+        SavedGame {
+            names: (0..n).map(|i| format!("Player{i}")).collect(),
+            avatars: vec![],
+            myself: 0,
+            snapshot: Snapshot {
+                state: GameState {
+                    round: Round::Preflop,
+                    community: vec![],
+                    players_bet: vec![0; n],
+                    players_money: vec![100; n],
+                    bet_amount: 1,
+                    players_all_in: vec![],
+                    folded_players: vec![],
+                    num_active_players: n as i32,
+                    active_players: (0..n).collect(),
+                },
+                deck: vec![],
+                hands: vec![(card, card); n],
+                pending: (0..n).collect(),
+                raising: false,
+            },
+            setup: GameSetup::default(),
+        }
+    }
+
+    #[test]
+    fn broken_saves() {
+        assert!(saved(3).check().is_ok());
+
+        let mut save = saved(3);
+        save.myself = 3;
+        assert_eq!(save.check().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut save = saved(3);
+        save.names.pop();
+        assert!(save.check().is_err());
+
+        let mut save = saved(3);
+        save.snapshot.pending = vec![1, 5];
+        assert!(save.check().is_err());
+    }
+}
@@ -1,18 +1,106 @@
-use sdl2::{pixels::Color, rect::Rect};
+use std::time::Duration;
+
+use sdl2::{
+    event::Event,
+    pixels::Color,
+    rect::{Point, Rect},
+};
 
 use crate::{
     core::state::GameState,
     graphic::{
         button::{Button, ButtonState},
-        self_render::{CALL_COLOR, FOLD_COLOR, RAISE_COLOR},
+        chips::ChipSet,
+        font::DEFAULT_FONT,
+        self_render::{CALL_COLOR, FOLD_COLOR, PRESET_COLOR, RAISE_COLOR},
         slider::Slider,
+        text_input::TextInput,
+        tween::{deck_point, Pose, Tween},
         ui_component::EventReceiver,
         HEIGHT, WIDTH,
     },
+    settings::KeyBindings,
 };
 
+use super::assistant::Advice;
 use super::player_state::{PlayerAction, PlayerState};
 
+///Part of the slider moved by every step of the mouse wheel
+const WHEEL_STEP: f32 = 0.05;
+
+///A decision taken before the turn, played when the engine asks for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreAction {
+    ///Checks if there's nothing to call, folds otherwise
+    CheckFold,
+    Check,
+    ///Calls whatever the bet is
+    CallAny,
+    ///Calls only this amount
+    Call(i32),
+}
+
+impl PreAction {
+    ///The action for the amount to call, None if it no longer applies and the player decides
+    pub fn resolve(&self, diff: i32) -> Option<PlayerAction> {
+        match *self {
+            PreAction::CheckFold if diff == 0 => Some(PlayerAction::Call),
+            PreAction::CheckFold => Some(PlayerAction::Fold),
+            PreAction::Check => (diff == 0).then_some(PlayerAction::Call),
+            PreAction::CallAny => Some(PlayerAction::Call),
+            PreAction::Call(amount) => (diff == amount).then_some(PlayerAction::Call),
+        }
+    }
+
+    ///Checking and calling an amount don't make sense after the bet changes
+    fn survives_bet(&self) -> bool {
+        matches!(self, PreAction::CheckFold | PreAction::CallAny)
+    }
+}
+
+///Raises that are set with a button, the amount is over the call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaisePreset {
+    Min,
+    HalfPot,
+    ThreeQuartersPot,
+    Pot,
+    AllIn,
+}
+
+impl RaisePreset {
+    pub const ALL: [RaisePreset; 5] = [
+        RaisePreset::Min,
+        RaisePreset::HalfPot,
+        RaisePreset::ThreeQuartersPot,
+        RaisePreset::Pot,
+        RaisePreset::AllIn,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RaisePreset::Min => "MIN",
+            RaisePreset::HalfPot => "1/2 POT",
+            RaisePreset::ThreeQuartersPot => "3/4 POT",
+            RaisePreset::Pot => "POT",
+            RaisePreset::AllIn => "ALL-IN",
+        }
+    }
+
+    ///The raise over the call, the pot is counted after calling and nobody raises more than the max
+    pub fn amount(&self, blind: i32, pot: i32, diff: i32, max: i32) -> i32 {
+        let pot = pot + diff;
+        let amount = match self {
+            RaisePreset::Min => blind,
+            RaisePreset::HalfPot => pot / 2,
+            RaisePreset::ThreeQuartersPot => pot * 3 / 4,
+            RaisePreset::Pot => pot,
+            RaisePreset::AllIn => max,
+        };
+        amount.clamp(0, max.max(0))
+    }
+}
+
 pub struct SelfController {
     pub bounds: Rect,
 
@@ -20,24 +108,96 @@ pub struct SelfController {
     pub call_btn: Button,
     pub fold_btn: Button,
     pub slider: Slider,
+    pub preset_btns: Vec<Button>,
+    ///Check/fold, check, call any and call, shown out of turn
+    pub pre_btns: Vec<Button>,
+    ///Where the exact raise is typed
+    pub raise_input: TextInput,
     pub image_bounds: Rect,
 
     pub state: PlayerState,
     pub diff: i32,
+    ///The chips in the pot, with the bets of this round
+    pub pot: i32,
+    pub blind: i32,
+    ///The raise over the call, it follows the slider, the presets and the typed amount
+    raise: i32,
+    pub keys: KeyBindings,
+    ///The cards that are being dealt
+    pub hand_tweens: [Option<Tween>; 2],
+    pub chip_set: ChipSet,
+
+    ///The odds of the hand, shown when the assistant is on
+    pub advice: Option<Advice>,
+    pub pre_action: Option<PreAction>,
+    ///The bet when the pre-action was chosen
+    pre_bet: i32,
+    ///An action was sent in this turn, nothing else is sent until the next one
+    acted: bool,
 }
 
 impl EventReceiver<Option<PlayerAction>> for SelfController {
-    fn handle_event(&mut self, event: &sdl2::event::Event) -> Option<PlayerAction> {
-        self.slider.handle_event(event);
+    fn handle_event(&mut self, event: &Event) -> Option<PlayerAction> {
+        let max = self.max_raise();
+
+        let value = self.slider.value();
+        if self.slider.handle_event(event) != value {
+            self.set_raise((self.slider.value() * max as f32).round() as i32);
+        }
+
+        if self.raise_input.handle_event(event) {
+            self.raise = self.raise_input.text().parse::<i32>().unwrap_or(0).min(max);
+            if max > 0 {
+                self.slider.set_value(self.raise as f32 / max as f32);
+            }
+        }
+
+        if let Event::MouseWheel { y, .. } = event {
+            if self.state.can_raise {
+                let value = self.slider.value() + WHEEL_STEP * *y as f32;
+                self.set_raise((value.clamp(0.0, 1.0) * max as f32).round() as i32);
+            }
+        }
+
+        if !self.state.turn {
+            self.handle_pre_action(event);
+            return None;
+        }
 
-        let raise =
+        let mut preset = None;
+        for (btn, p) in self.preset_btns.iter_mut().zip(RaisePreset::ALL) {
+            if btn.handle_event(event) == ButtonState::Pressed {
+                preset = Some(p);
+            }
+        }
+        if let Some(p) = preset.filter(|_| self.state.can_raise) {
+            self.set_raise(p.amount(self.blind, self.pot, self.diff, max));
+        }
+
+        let mut raise =
             self.raise_btn.handle_event(event) == ButtonState::Pressed && self.state.can_raise;
-        let call = self.call_btn.handle_event(event) == ButtonState::Pressed;
-        let fold = self.fold_btn.handle_event(event) == ButtonState::Pressed;
+        let mut call = self.call_btn.handle_event(event) == ButtonState::Pressed;
+        let mut fold = self.fold_btn.handle_event(event) == ButtonState::Pressed;
+
+        //The hotkeys would be typed in the raise
+        if let Event::KeyDown {
+            keycode: Some(key), ..
+        } = event
+        {
+            if !self.raise_input.is_focused() {
+                raise |= *key == self.keys.raise.0 && self.state.can_raise;
+                call |= *key == self.keys.call.0;
+                fold |= *key == self.keys.fold.0;
+            }
+        }
 
         if !self.state.can_raise {
             self.raise_btn.set_inactive();
             self.slider.set_inactive();
+            for btn in self.preset_btns.iter_mut() {
+                btn.set_inactive();
+            }
+            self.set_raise(0);
         }
 
         if self.state.folded {
@@ -47,9 +207,13 @@ impl EventReceiver<Option<PlayerAction>> for SelfController {
             return None;
         }
 
-        if raise {
+        if self.acted {
+            return None;
+        }
+
+        let action = if raise {
             let s = Some(PlayerAction::Raise(self.to_raise()));
-            self.slider.reset();
+            self.set_raise(0);
             s
         } else if call {
             Some(PlayerAction::Call)
@@ -57,7 +221,9 @@ impl EventReceiver<Option<PlayerAction>> for SelfController {
             Some(PlayerAction::Fold)
         } else {
             None
-        }
+        };
+        self.acted = action.is_some();
+        action
     }
 }
 
@@ -83,11 +249,35 @@ impl Default for SelfController {
             CALL_COLOR,
         );
         let slider = Slider::new(
-            Rect::new(bounds.right() - 750, y + 50, 700, 30),
+            Rect::new(bounds.right() - 750, y + 50, 480, 30),
             Color::BLUE,
             Color::GRAY,
             Color::BLACK,
         );
+        let preset_btns = RaisePreset::ALL
+            .iter()
+            .enumerate()
+            .map(|(k, p)| {
+                let bounds = Rect::new(bounds.right() - 750 + 142 * k as i32, y + 5, 130, 35);
+                Button::new(p.name().to_string(), bounds, PRESET_COLOR)
+            })
+            .collect();
+        let mut raise_input = TextInput::new(
+            "0".to_string(),
+            Rect::new(bounds.right() - 250, y + 45, 200, 40),
+            Color::RGB(40, 40, 40),
+            Color::RGB(80, 80, 80),
+        )
+        .numeric();
+        raise_input.set_font(DEFAULT_FONT.derive_size(24));
+        let pre_btns = ["CHECK/FOLD", "CHECK", "CALL ANY", "CALL"]
+            .iter()
+            .enumerate()
+            .map(|(k, name)| {
+                let bounds = Rect::new(bounds.right() - 750 + 177 * k as i32, y + 5, 165, 35);
+                Button::new(name.to_string(), bounds, PRESET_COLOR)
+            })
+            .collect();
         let image_bounds = Rect::new(50, y + 50, 150, 150);
 
         SelfController {
@@ -96,26 +286,189 @@ impl Default for SelfController {
             call_btn,
             fold_btn,
             slider,
+            preset_btns,
+            pre_btns,
+            raise_input,
             image_bounds,
             state: Default::default(),
             diff: 0,
+            pot: 0,
+            blind: 1,
+            raise: 0,
+            keys: KeyBindings::default(),
+            hand_tweens: [None; 2],
+            chip_set: ChipSet::default(),
+            advice: None,
+            pre_action: None,
+            pre_bet: 0,
+            acted: false,
         }
     }
 }
 
 impl SelfController {
     pub fn set_state(&mut self, state: PlayerState) {
+        if !state.turn {
+            self.acted = false;
+        }
+        if state.folded || state.all_in {
+            self.pre_action = None;
+        }
         self.state = state;
     }
 
+    ///Toggles the pre-actions, choosing the same one again clears it
+    fn handle_pre_action(&mut self, event: &Event) {
+        let mut chosen = None;
+        for (k, btn) in self.pre_btns.iter_mut().enumerate() {
+            if btn.handle_event(event) == ButtonState::Pressed {
+                chosen = match k {
+                    0 => Some(PreAction::CheckFold),
+                    1 if self.diff == 0 => Some(PreAction::Check),
+                    2 => Some(PreAction::CallAny),
+                    3 if self.diff > 0 => Some(PreAction::Call(self.diff)),
+                    _ => None,
+                };
+            }
+        }
+
+        if let Some(pre) = chosen.filter(|_| !self.state.folded) {
+            self.pre_action = if self.pre_action == Some(pre) {
+                None
+            } else {
+                Some(pre)
+            };
+            self.pre_bet = self.state.bet + self.diff;
+        }
+    }
+
+    ///The engine has played the decision, the next turn can be played again
+    pub fn end_turn(&mut self) {
+        self.acted = false;
+    }
+
+    ///The action of the pre-action once it's the turn of the player, it's used only once
+    pub fn take_pre_action(&mut self) -> Option<PlayerAction> {
+        if !self.state.turn || self.acted {
+            return None;
+        }
+
+        let action = self.pre_action.take()?.resolve(self.diff);
+        self.acted = action.is_some();
+        action
+    }
+
     pub fn to_raise(&self) -> i32 {
-        (self.slider.value() * (self.state.cash - self.diff) as f32) as i32
+        self.raise.clamp(0, self.max_raise())
+    }
+
+    ///Going all-in
+    fn max_raise(&self) -> i32 {
+        (self.state.cash - self.diff).max(0)
+    }
+
+    ///Moves the slider and writes the amount in the text field
+    fn set_raise(&mut self, raise: i32) {
+        let max = self.max_raise();
+        self.raise = raise.clamp(0, max);
+        self.slider
+            .set_value(if max > 0 { self.raise as f32 / max as f32 } else { 0.0 });
+        self.raise_input.set_text(self.raise.to_string());
+    }
+
+    ///The cards lean apart from the center of the box
+    pub fn card_center(&self, k: usize) -> Point {
+        let dx = if k == 0 { -70 } else { 70 };
+        self.bounds.center().offset(dx, -30)
+    }
+
+    ///Slides the cards from the deck and turns them over, the second one after the first
+    pub fn deal(&mut self, length: Duration) {
+        let from = Pose::at(deck_point()).with_scale(0.3).with_opacity(0.0).face_down();
+        self.hand_tweens = [0, 1].map(|k| {
+            let tween = Tween::new(from, Pose::at(self.card_center(k)), length);
+            Some(tween.with_wait(length.mul_f32(k as f32 / 2.0)))
+        });
+    }
+
+    pub fn update(&mut self, delta: &Duration) {
+        for slot in self.hand_tweens.iter_mut() {
+            if let Some(tween) = slot {
+                tween.update(delta);
+                if tween.is_done() {
+                    *slot = None;
+                }
+            }
+        }
     }
 
     pub fn early_update(&mut self, state: &GameState) {
         if self.state.can_raise {
             self.raise_btn.set_active();
+            for btn in self.preset_btns.iter_mut() {
+                btn.set_active();
+            }
         }
         self.diff = state.bet_amount - self.state.bet;
+        self.pot = state.players_bet.iter().sum();
+
+        if state.bet_amount != self.pre_bet && !self.pre_action.is_some_and(|p| p.survives_bet()) {
+            self.pre_action = None;
+        }
+
+        self.pre_btns[1].set_text(if self.diff == 0 { "CHECK" } else { "-" }.to_string());
+        self.pre_btns[3].set_text(if self.diff > 0 {
+            format!("CALL {}", self.diff)
+        } else {
+            "-".to_string()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_amounts() {
+        //A pot of 300 with 100 to call
+        let amount = |p: RaisePreset| p.amount(10, 300, 100, 1000);
+        assert_eq!(amount(RaisePreset::Min), 10);
+        assert_eq!(amount(RaisePreset::HalfPot), 200);
+        assert_eq!(amount(RaisePreset::ThreeQuartersPot), 300);
+        assert_eq!(amount(RaisePreset::Pot), 400);
+        assert_eq!(amount(RaisePreset::AllIn), 1000);
+
+        //Nobody raises more than the stack
+        assert_eq!(RaisePreset::Pot.amount(10, 300, 100, 250), 250);
+        assert_eq!(RaisePreset::Min.amount(10, 300, 100, 0), 0);
+    }
+
+    #[test]
+    fn pre_actions() {
+        assert_eq!(PreAction::CheckFold.resolve(0), Some(PlayerAction::Call));
+        assert_eq!(PreAction::CheckFold.resolve(50), Some(PlayerAction::Fold));
+        assert_eq!(PreAction::Check.resolve(50), None);
+        assert_eq!(PreAction::CallAny.resolve(500), Some(PlayerAction::Call));
+        assert_eq!(PreAction::Call(50).resolve(50), Some(PlayerAction::Call));
+        assert_eq!(PreAction::Call(50).resolve(150), None);
     }
+
+    #[test]
+    fn pre_action_is_played_once_in_turn() {
+        let mut controller = SelfController::default();
+        controller.pre_action = Some(PreAction::CallAny);
+        assert_eq!(controller.take_pre_action(), None);
+
+        controller.set_state(PlayerState {
+            turn: true,
+            ..Default::default()
+        });
+        assert_eq!(controller.take_pre_action(), Some(PlayerAction::Call));
+        assert_eq!(controller.pre_action, None);
+
+        controller.pre_action = Some(PreAction::CallAny);
+        assert_eq!(controller.take_pre_action(), None);
+    }
+}
 }
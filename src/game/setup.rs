@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::clock::TimeLimit;
+use crate::core::player::dummy::DummyPlayer;
+use crate::core::player::montecarlo::{MontecarloPlayer, SIM_ROUNDS};
+use crate::core::player::Player;
+use crate::graphic::ui::MAX_PLAYERS;
+use crate::settings::Settings;
+
+use super::TIME_LIMIT;
+
+pub const MAX_OPPONENTS: usize = MAX_PLAYERS - 1;
+///Blinds that can be chosen in the setup screen
+pub const BLINDS: [i32; 6] = [1, 10, 50, 100, 500, 1000];
+///Time limits that can be chosen in the settings, in seconds
+pub const TIME_LIMITS: [Option<u64>; 4] = [None, Some(15), Some(30), Some(60)];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Variant {
+    #[default]
+    TexasHoldem,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 1] = [Variant::TexasHoldem];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::TexasHoldem => "Texas Hold'em",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 2] = [Difficulty::Easy, Difficulty::Hard];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    ///The bot that plays in every opponent seat, the hard ones simulate the given games
+    pub fn bot(&self, sim_rounds: i32) -> Box<dyn Player> {
+        match self {
+            Difficulty::Easy => Box::<DummyPlayer>::default(),
+            Difficulty::Hard => Box::new(MontecarloPlayer::default().with_sim_rounds(sim_rounds)),
+        }
+    }
+}
+
+///A seat of the table edited in the setup screen, an empty name or no picture keep the defaults
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Seat {
+    pub name: String,
+    pub avatar: Option<String>,
+}
+
+///The table chosen in the setup screen, the local player brings the bankroll of the profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSetup {
+    pub opponents: usize,
+    ///Money of the opponents at the start
+    pub stack: i32,
+    pub blind: i32,
+    pub variant: Variant,
+    pub difficulty: Difficulty,
+    ///The decisions aren't timed if there's no limit
+    pub time_limit: Option<TimeLimit>,
+    ///Simulations of the hard bots before every decision
+    pub sim_rounds: i32,
+}
+
+impl Default for GameSetup {
+    fn default() -> Self {
+        GameSetup {
+            opponents: 7,
+            stack: 100000,
+            blind: BLINDS[0],
+            variant: Variant::default(),
+            difficulty: Difficulty::default(),
+            time_limit: Some(TIME_LIMIT),
+            sim_rounds: SIM_ROUNDS,
+        }
+    }
+}
+
+impl GameSetup {
+    ///A table with the preferred bots and time limit
+    pub fn from_settings(settings: &Settings) -> Self {
+        GameSetup {
+            difficulty: settings.difficulty,
+            time_limit: GameSetup::time_limit_from(settings.time_limit),
+            sim_rounds: settings.sim_rounds,
+            ..Default::default()
+        }
+    }
+
+    ///The number of opponents for a slider value from 0 to 1
+    pub fn opponents_from(value: f32) -> usize {
+        1 + (value.clamp(0.0, 1.0) * (MAX_OPPONENTS - 1) as f32).round() as usize
+    }
+
+    ///The blind for a slider value from 0 to 1
+    pub fn blind_from(value: f32) -> i32 {
+        BLINDS[(value.clamp(0.0, 1.0) * (BLINDS.len() - 1) as f32).round() as usize]
+    }
+
+    ///The time limit with the decision time in seconds, the time bank is as long as a decision
+    pub fn time_limit_from(secs: Option<u64>) -> Option<TimeLimit> {
+        secs.map(std::time::Duration::from_secs).map(|t| TimeLimit {
+            decision: t,
+            bank: t,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slider_values() {
+        assert_eq!(GameSetup::opponents_from(0.0), 1);
+        assert_eq!(GameSetup::opponents_from(1.0), MAX_OPPONENTS);
+        assert_eq!(GameSetup::opponents_from(0.5), 5);
+
+        assert_eq!(GameSetup::blind_from(0.0), BLINDS[0]);
+        assert_eq!(GameSetup::blind_from(1.0), BLINDS[BLINDS.len() - 1]);
+
+        assert_eq!(GameSetup::time_limit_from(None), None);
+        assert_eq!(GameSetup::time_limit_from(Some(30)), Some(TIME_LIMIT));
+    }
+
+    #[test]
+    fn saved_setup() {
+        let setup = GameSetup {
+            blind: 50,
+            difficulty: Difficulty::Easy,
+            time_limit: None,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&setup).unwrap();
+        assert_eq!(serde_json::from_str::<GameSetup>(&json).unwrap(), setup);
+
+        //The missing fields take their default values
+        let setup: GameSetup = serde_json::from_str(r#"{"blind": 500}"#).unwrap();
+        assert_eq!(setup.blind, 500);
+        assert_eq!(setup.sim_rounds, SIM_ROUNDS);
+    }
+}
@@ -0,0 +1,196 @@
+use sdl2::{
+    event::Event,
+    rect::{Point, Rect},
+};
+
+use crate::core::{
+    card::Card,
+    error::EngineError,
+    hand::Hand,
+    player::PlayerHand,
+    rank::Rank,
+};
+use crate::graphic::{
+    action_log::ActionLog,
+    button::{Button, ButtonState},
+    font::DEFAULT_FONT,
+    ui_component::{Drawable, EventReceiver},
+    SDL2Graphics, WIDTH,
+};
+
+use super::menu::MENU_COLOR;
+
+///A hand turned over at the showdown
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShownHand {
+    pub i: usize,
+    pub hand: PlayerHand,
+    pub rank: Rank,
+    ///The cards of the hand and of the table that make the rank
+    pub best: Vec<Card>,
+}
+
+///What happened in the last hand, shown in the results screen
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HandSummary {
+    ///The money of every player when the hand was dealt
+    pub start_stacks: Vec<i32>,
+    pub community: Vec<Card>,
+    pub shown: Vec<ShownHand>,
+    ///The winner and the amount of every pot
+    pub pots: Vec<(usize, i32)>,
+    ///Every action of the hand, like in the log
+    pub timeline: Vec<String>,
+}
+
+impl HandSummary {
+    pub fn new(start_stacks: Vec<i32>) -> Self {
+        HandSummary {
+            start_stacks,
+            ..Default::default()
+        }
+    }
+
+    pub fn show(
+        &mut self,
+        i: usize,
+        hand: PlayerHand,
+        rank: Rank,
+        community: &[Card],
+    ) -> Result<(), EngineError> {
+        let (_, best) = Hand::new_from_hand(hand, community).best_five()?;
+        self.community = community.to_vec();
+        self.shown.push(ShownHand {
+            i,
+            hand,
+            rank,
+            best,
+        });
+
+        Ok(())
+    }
+
+    pub fn award(&mut self, i: usize, pot: i32) {
+        self.pots.push((i, pot));
+    }
+
+    ///What the player took from the pots
+    pub fn won(&self, i: usize) -> i32 {
+        self.pots
+            .iter()
+            .filter(|&&(winner, _)| winner == i)
+            .map(|&(_, pot)| pot)
+            .sum()
+    }
+
+    ///How much every player won or lost, from the stacks after the hand
+    pub fn net(&self, end_stacks: &[i32]) -> Vec<i32> {
+        end_stacks
+            .iter()
+            .zip(&self.start_stacks)
+            .map(|(end, start)| end - start)
+            .collect()
+    }
+}
+
+///The button of the results screen and the timeline of the hand that it opens
+pub struct HandReview {
+    button: Button,
+    ///The actions of the hand, while they are reviewed
+    timeline: Option<ActionLog>,
+}
+
+impl Default for HandReview {
+    fn default() -> Self {
+        HandReview {
+            button: Button::new(
+                "REVIEW HAND".to_string(),
+                Rect::new(WIDTH as i32 / 2 - 150, 995, 300, 60),
+                MENU_COLOR,
+            ),
+            timeline: None,
+        }
+    }
+}
+
+impl Drawable for HandReview {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        if let Some(timeline) = &self.timeline {
+            gfx.draw_string(
+                "HAND REVIEW",
+                DEFAULT_FONT.derive_size(72),
+                Point::new(WIDTH as i32 / 2, 70),
+                true,
+            )?;
+            timeline.draw(gfx)?;
+        }
+
+        self.button.draw(gfx)
+    }
+}
+
+impl HandReview {
+    ///Opens and closes the timeline with the button, the timeline scrolls with the wheel
+    pub fn handle_event(&mut self, event: &Event, summary: &HandSummary) {
+        if let Some(timeline) = &mut self.timeline {
+            timeline.handle_event(event);
+        }
+
+        if self.button.handle_event(event) == ButtonState::Pressed {
+            if self.is_open() {
+                self.close();
+            } else {
+                let mut timeline = ActionLog::new(Rect::new(WIDTH as i32 / 2 - 500, 130, 1000, 840));
+                for entry in &summary.timeline {
+                    timeline.push(entry);
+                }
+                self.timeline = Some(timeline);
+                self.button.set_text("RESULTS".to_string());
+            }
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.timeline.is_some()
+    }
+
+    pub fn close(&mut self) {
+        self.timeline = None;
+        self.button.set_text("REVIEW HAND".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::card::{Suit, Value};
+
+    use super::*;
+
+    #[test]
+    fn pots_and_net_changes() -> Result<(), EngineError> {
+        let mut summary = HandSummary::new(vec![100, 100, 100]);
+        let community = [
+            Card::new(Suit::Heart, Value::Two),
+            Card::new(Suit::Heart, Value::Seven),
+            Card::new(Suit::Club, Value::Nine),
+            Card::new(Suit::Spade, Value::King),
+            Card::new(Suit::Diamond, Value::Four),
+        ];
+        let hand = (
+            Card::new(Suit::Club, Value::King),
+            Card::new(Suit::Club, Value::Three),
+        );
+        summary.show(1, hand, Rank::OnePair(Value::King), &community)?;
+        summary.award(1, 90);
+
+        assert_eq!(summary.shown[0].best.len(), 5);
+        assert!(summary.shown[0].best.contains(&hand.0));
+        assert!(!summary.shown[0].best.contains(&hand.1));
+
+        assert_eq!(summary.won(1), 90);
+        assert_eq!(summary.won(0), 0);
+        assert_eq!(summary.net(&[70, 160, 70]), vec![-30, 60, -30]);
+
+        Ok(())
+    }
+}
@@ -1,36 +1,119 @@
 extern crate sdl2;
-use std::{collections::HashMap, path::Path, time::Duration};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 
+use log::warn;
 use sdl2::{
-    image::LoadTexture,
-    pixels::Color,
+    image::{LoadSurface, LoadTexture},
+    pixels::{Color, PixelFormatEnum},
     rect::{Point, Rect},
     render::{Texture, TextureCreator, TextureQuery, WindowCanvas},
+    surface::Surface,
     ttf::Sdl2TtfContext,
-    video::WindowContext,
+    video::{FullscreenType, WindowContext, WindowPos},
 };
 
+use serde::{Deserialize, Serialize};
+
+use crate::theme::{CardSheet, Palette, Theme};
+
 use self::font::{FontParams, DEFAULT_FONT};
 
+pub mod action_log;
+pub mod avatar;
 pub mod button;
+pub mod chips;
 pub mod community_renderer;
+pub mod dropdown;
 pub mod font;
 pub mod player_render;
 pub mod renderer;
 pub mod self_render;
 pub mod slider;
+pub mod text_input;
+pub mod tween;
 pub mod ui;
 pub mod ui_component;
 
 pub const TITLE: &str = "Rust game";
+///The logical size of the screen, everything is drawn in these coordinates and scaled to the window
 pub const WIDTH: u32 = 1920;
 pub const HEIGHT: u32 = 1080;
 
-pub const TEXTURE_PATHS: [(&str, &str); 3] = [
-    ("BACKGROUND","assets/vecteezy_poker-table-green-cloth-on-dark-background-vector-illustration_6325236.jpg"),
-    ("CARD","assets/cards.png"),
-    ("TITLE","assets/title-screen.jpg"),
+///Window sizes that can be chosen in the settings
+pub const RESOLUTIONS: [(u32, u32); 5] = [
+    (1280, 720),
+    (1600, 900),
+    (1920, 1080),
+    (2560, 1440),
+    (3840, 2160),
 ];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowMode {
+    Windowed,
+    #[default]
+    Fullscreen,
+    ///A window without borders that covers the desktop
+    Borderless,
+}
+
+impl WindowMode {
+    pub const ALL: [WindowMode; 3] = [
+        WindowMode::Windowed,
+        WindowMode::Fullscreen,
+        WindowMode::Borderless,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindowMode::Windowed => "Windowed",
+            WindowMode::Fullscreen => "Fullscreen",
+            WindowMode::Borderless => "Borderless",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Display {
+    pub mode: WindowMode,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Display {
+            mode: WindowMode::default(),
+            width: WIDTH,
+            height: HEIGHT,
+        }
+    }
+}
+
+impl Display {
+    ///Changes the saved display with --windowed, --borderless and --resolution <width>x<height>
+    pub fn with_args(mut self, args: &[String]) -> Self {
+        if args.iter().any(|a| a == "--windowed") {
+            self.mode = WindowMode::Windowed;
+        } else if args.iter().any(|a| a == "--borderless") {
+            self.mode = WindowMode::Borderless;
+        }
+
+        let resolution = args
+            .iter()
+            .position(|a| a == "--resolution")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|r| r.split_once('x'))
+            .and_then(|(w, h)| Some((w.parse().ok()?, h.parse().ok()?)));
+        if let Some((width, height)) = resolution {
+            self.width = width;
+            self.height = height;
+        }
+
+        self
+    }
+}
+
 pub const FONTS: [FontParams; 10] = [
     DEFAULT_FONT,
     DEFAULT_FONT.derive_size(24),
@@ -52,41 +135,53 @@ pub const START_DELAY: Duration = Duration::from_millis(1000);
 pub const PLAY_DELAY: Duration = Duration::from_millis(1000);
 pub const DEAL_DELAY: Duration = Duration::from_millis(300);
 pub const SHOWDOWN_DELAY: Duration = Duration::from_millis(1000);
+///Lengths of the animations at the normal speed
+pub const CARD_ANIMATION: Duration = Duration::from_millis(300);
+pub const FLIP_ANIMATION: Duration = Duration::from_millis(300);
+pub const CHIPS_ANIMATION: Duration = Duration::from_millis(500);
 
 pub struct SDL2Graphics<'a> {
     pub canvas: WindowCanvas,
     ttf: Sdl2TtfContext,
-    font_path: &'a Path,
+    font_path: PathBuf,
     pub tex_cache: HashMap<&'a str, Texture<'a>>,
     pub font_cache: HashMap<(FontParams, char), Texture<'a>>,
+    ///The pictures loaded while drawing, none if the file couldn't be loaded
+    image_cache: HashMap<String, Option<Texture<'a>>>,
+    creator: Option<&'a TextureCreator<WindowContext>>,
+    ///Where the cards are in the CARD texture
+    pub card_sheet: CardSheet,
+    ///The back design of the sheet that is drawn
+    pub card_back: usize,
+    palette: Palette,
 }
 
 impl<'a> SDL2Graphics<'a> {
-    pub fn new(
-        mut canvas: WindowCanvas,
-        ttf: Sdl2TtfContext,
-        font_path: &'a Path,
-        creator: &'a TextureCreator<WindowContext>,
-    ) -> Self {
+    pub fn new(mut canvas: WindowCanvas, ttf: Sdl2TtfContext) -> Self {
         canvas.set_blend_mode(sdl2::render::BlendMode::Blend);
-        let mut gfx = SDL2Graphics {
+        //SDL scales the logical screen to the window, with black bars if the aspect ratio differs
+        canvas
+            .set_logical_size(WIDTH, HEIGHT)
+            .expect("Couldn't set the logical size");
+
+        let theme = Theme::default();
+        SDL2Graphics {
             canvas,
             ttf,
-            font_path,
+            font_path: theme.path(&theme.font),
             tex_cache: HashMap::new(),
             font_cache: HashMap::new(),
-        };
-
-        for (name, path) in TEXTURE_PATHS {
-            if let Ok(tex) = creator.load_texture(path) {
-                gfx.tex_cache.insert(name, tex);
-            }
+            image_cache: HashMap::new(),
+            creator: None,
+            card_sheet: theme.cards,
+            card_back: 0,
+            palette: theme.colors,
         }
-
-        gfx
     }
 
     pub fn start(&mut self, creator: &'a TextureCreator<WindowContext>) -> Result<(), String> {
+        self.creator = Some(creator);
+
         //Load fonts
         for params in FONTS {
             self.load_font(creator, params)?;
@@ -95,13 +190,84 @@ impl<'a> SDL2Graphics<'a> {
         Ok(())
     }
 
+    ///Loads the images and the font of the theme, the fonts are loaded again if they were
+    ///already loaded by [`SDL2Graphics::start`]
+    pub fn set_theme(
+        &mut self,
+        creator: &'a TextureCreator<WindowContext>,
+        theme: &Theme,
+        four_color: bool,
+        back: usize,
+    ) -> Result<(), String> {
+        //Without a background the felt colour is painted
+        for (name, file) in [("BACKGROUND", &theme.background), ("TITLE", &theme.title)] {
+            if file.is_empty() {
+                self.tex_cache.remove(name);
+                continue;
+            }
+            match creator.load_texture(theme.path(file)) {
+                Ok(tex) => {
+                    self.tex_cache.insert(name, tex);
+                }
+                Err(e) => {
+                    warn!("Couldn't load {file} of the theme {}: {e}", theme.name);
+                    self.tex_cache.remove(name);
+                }
+            }
+        }
+
+        let cards = load_cards(creator, theme, four_color)?;
+        self.tex_cache.insert("CARD", cards);
+        self.card_sheet = theme.cards.clone();
+        self.card_back = back;
+
+        let font_path = theme.path(&theme.font);
+        let reload = font_path != self.font_path || theme.colors.text != self.palette.text;
+        self.font_path = font_path;
+        self.palette = theme.colors;
+        if reload && !self.font_cache.is_empty() {
+            self.font_cache.clear();
+            self.start(creator)?;
+        }
+
+        Ok(())
+    }
+
     pub fn show(&mut self) {
         self.canvas.present();
     }
 
+    ///Changes the size and the mode of the window, the logical screen keeps its size
+    pub fn set_display(&mut self, display: Display) -> Result<(), String> {
+        let window = self.canvas.window_mut();
+        window.set_fullscreen(FullscreenType::Off)?;
+        window
+            .set_size(display.width, display.height)
+            .map_err(|e| e.to_string())?;
+
+        match display.mode {
+            WindowMode::Windowed => {
+                window.set_bordered(true);
+                window.set_position(WindowPos::Centered, WindowPos::Centered);
+            }
+            WindowMode::Fullscreen => window.set_fullscreen(FullscreenType::True)?,
+            WindowMode::Borderless => window.set_fullscreen(FullscreenType::Desktop)?,
+        }
+
+        Ok(())
+    }
+
     pub fn clear(&mut self) -> Result<(), String> {
-        if let Some(bg) = self.tex_cache.get("BACKGROUND") {
-            self.canvas.copy(bg, None, None)?;
+        //The letterbox bars are outside of the background
+        self.canvas.set_draw_color(Color::BLACK);
+        self.canvas.clear();
+
+        match self.tex_cache.get("BACKGROUND") {
+            Some(bg) => self.canvas.copy(bg, None, None)?,
+            None => {
+                let felt = self.palette.felt();
+                self.draw_rect(Rect::new(0, 0, WIDTH, HEIGHT), felt)?;
+            }
         }
 
         Ok(())
@@ -114,6 +280,55 @@ impl<'a> SDL2Graphics<'a> {
         Ok(())
     }
 
+    ///Draws the picture scaled to the rect, it's loaded the first time it's drawn.
+    ///<br>False if it can't be loaded
+    pub fn draw_image(&mut self, path: &str, dst: Rect) -> Result<bool, String> {
+        let Some(creator) = self.creator else {
+            return Ok(false);
+        };
+        if !self.image_cache.contains_key(path) {
+            let tex = creator
+                .load_texture(path)
+                .map_err(|e| warn!("Couldn't load the picture {path}: {e}"))
+                .ok();
+            self.image_cache.insert(path.to_string(), tex);
+        }
+
+        match self.image_cache.get(path) {
+            Some(Some(tex)) => {
+                self.canvas.copy(tex, None, dst)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    ///Draws the part of a ring from the top clockwise, the fraction goes from 0 to 1
+    pub fn draw_ring(
+        &mut self,
+        center: Point,
+        radius: i32,
+        fraction: f32,
+        color: Color,
+    ) -> Result<(), String> {
+        const THICKNESS: u32 = 6;
+        const STEPS: i32 = 120;
+
+        self.canvas.set_draw_color(color);
+        let steps = (STEPS as f32 * fraction.clamp(0.0, 1.0)) as i32;
+        for k in 0..steps {
+            let angle = k as f32 / STEPS as f32 * std::f32::consts::TAU;
+            let p = center.offset(
+                (radius as f32 * angle.sin()) as i32,
+                -(radius as f32 * angle.cos()) as i32,
+            );
+            self.canvas
+                .fill_rect(Rect::from_center(p, THICKNESS, THICKNESS))?;
+        }
+
+        Ok(())
+    }
+
     pub fn draw_string(
         &mut self,
         txt: &str,
@@ -156,13 +371,19 @@ impl<'a> SDL2Graphics<'a> {
     ) -> Result<(), String> {
         let font = self
             .ttf
-            .load_font(self.font_path, params.size)
+            .load_font(&self.font_path, params.size)
             .map_err(|e| e.to_string())?;
 
+        //The white text takes the colour of the theme
+        let color = if params.color == Color::WHITE {
+            self.palette.text()
+        } else {
+            params.color
+        };
         for c in CHARACTERS.chars() {
             let surf = font
                 .render(&c.to_string())
-                .blended(params.color)
+                .blended(color)
                 .map_err(|e| e.to_string())?;
 
             let tex = creator
@@ -175,6 +396,29 @@ impl<'a> SDL2Graphics<'a> {
     }
 }
 
+///The sprite sheet of the theme, with the suits recoloured for the four-colour deck when the
+///theme doesn't have its own sheet
+fn load_cards<'a>(
+    creator: &'a TextureCreator<WindowContext>,
+    theme: &Theme,
+    four_color: bool,
+) -> Result<Texture<'a>, String> {
+    let sheet = &theme.cards;
+    match (four_color, &sheet.four_color_path) {
+        (true, Some(path)) => creator.load_texture(theme.path(path)),
+        (true, None) => {
+            let mut surface =
+                Surface::from_file(theme.path(&sheet.path))?.convert_format(PixelFormatEnum::RGBA32)?;
+            let pitch = surface.pitch() as usize;
+            surface.with_lock_mut(|pixels| sheet.recolor_suits(pixels, pitch));
+            creator
+                .create_texture_from_surface(&surface)
+                .map_err(|e| e.to_string())
+        }
+        (false, _) => creator.load_texture(theme.path(&sheet.path)),
+    }
+}
+
 pub fn texture_size(texture: &Texture) -> (u32, u32) {
     let TextureQuery { width, height, .. } = texture.query();
     (width, height)
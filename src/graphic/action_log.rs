@@ -0,0 +1,220 @@
+use sdl2::{
+    event::Event,
+    pixels::Color,
+    rect::{Point, Rect},
+};
+
+use super::{
+    font::DEFAULT_FONT,
+    ui_component::{Drawable, EventReceiver},
+    SDL2Graphics,
+};
+
+///The oldest lines are forgotten after these many
+const MAX_LINES: usize = 300;
+const LINE_H: i32 = 24;
+const PADDING: i32 = 10;
+///Width of the widest character of the default font, to know where the lines wrap
+const CHAR_W: u32 = 13;
+///The wrapped part of an entry is indented
+const INDENT: &str = "  ";
+
+///A panel with everything that happened in the last hands, the newest entry at the bottom.
+///<br>It scrolls with the mouse wheel while the mouse is over it
+pub struct ActionLog {
+    bounds: Rect,
+    lines: Vec<String>,
+    ///How many lines are hidden below the bottom of the panel
+    scroll: usize,
+    hovered: bool,
+}
+
+impl Default for ActionLog {
+    fn default() -> Self {
+        ActionLog::new(Rect::new(20, 20, 440, 250))
+    }
+}
+
+impl EventReceiver<bool> for ActionLog {
+    ///True when the event was used by the log, so the rest of the table ignores it
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::MouseMotion { x, y, .. } => {
+                self.hovered = self.bounds.contains_point((*x, *y));
+                false
+            }
+            Event::MouseWheel { y, .. } if self.hovered => {
+                self.scroll_by(*y);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Drawable for ActionLog {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        gfx.draw_rect(self.bounds, Color::RGBA(0, 0, 0, 150))?;
+
+        for (k, line) in self.visible_lines().iter().enumerate() {
+            gfx.draw_string(
+                line,
+                DEFAULT_FONT,
+                Point::new(
+                    self.bounds.x + PADDING,
+                    self.bounds.y + PADDING + k as i32 * LINE_H,
+                ),
+                false,
+            )?;
+        }
+
+        //How far up the log is scrolled
+        if self.scroll > 0 {
+            let total = self.lines.len() as f32;
+            let h = self.bounds.height() as f32;
+            let bar_h = (h * self.rows() as f32 / total).max(10.0);
+            let bottom = h - h * self.scroll as f32 / total;
+            gfx.draw_rect(
+                Rect::new(
+                    self.bounds.right() - 6,
+                    self.bounds.y + (bottom - bar_h).max(0.0) as i32,
+                    4,
+                    bar_h as u32,
+                ),
+                Color::RGBA(255, 255, 255, 150),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ActionLog {
+    pub fn new(bounds: Rect) -> Self {
+        ActionLog {
+            bounds,
+            lines: vec![],
+            scroll: 0,
+            hovered: false,
+        }
+    }
+
+    ///Adds an entry at the bottom, a scrolled log keeps showing the same lines
+    pub fn push(&mut self, entry: &str) {
+        let lines = wrap(entry, self.columns());
+        if self.scroll > 0 {
+            self.scroll += lines.len();
+        }
+        self.lines.extend(lines);
+
+        let extra = self.lines.len().saturating_sub(MAX_LINES);
+        self.lines.drain(..extra);
+        self.scroll = self.scroll.min(self.max_scroll());
+    }
+
+    ///Scrolls up for positive amounts, like the mouse wheel
+    pub fn scroll_by(&mut self, amount: i32) {
+        self.scroll = self
+            .scroll
+            .saturating_add_signed(amount as isize)
+            .min(self.max_scroll());
+    }
+
+    ///The lines that fit in the panel
+    pub fn visible_lines(&self) -> &[String] {
+        let end = self.lines.len() - self.scroll;
+        &self.lines[end.saturating_sub(self.rows())..end]
+    }
+
+    fn rows(&self) -> usize {
+        ((self.bounds.height() as i32 - 2 * PADDING) / LINE_H).max(1) as usize
+    }
+
+    fn columns(&self) -> usize {
+        (self.bounds.width().saturating_sub(2 * PADDING as u32) / CHAR_W).max(1) as usize
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.rows())
+    }
+}
+
+///Breaks the text in lines of at most the width in characters, between the words when it's
+///possible. The lines after the first are indented
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word = word.chars().collect::<Vec<_>>();
+        loop {
+            let indent = if lines.is_empty() { "" } else { INDENT };
+            if line.is_empty() {
+                line.push_str(indent);
+            }
+
+            let len = line.chars().count();
+            let sep = usize::from(len > indent.len());
+            if len + sep + word.len() <= width {
+                if sep == 1 {
+                    line.push(' ');
+                }
+                line.extend(&word);
+                break;
+            }
+
+            if len > indent.len() {
+                lines.push(std::mem::take(&mut line));
+                continue;
+            }
+
+            //The word doesn't fit in an empty line, so it's cut
+            let fits = width.saturating_sub(len).clamp(1, word.len());
+            line.extend(word.drain(..fits));
+            lines.push(std::mem::take(&mut line));
+            if word.is_empty() {
+                break;
+            }
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_lines() {
+        assert_eq!(wrap("Flop: Ah 7c 2d", 20), vec!["Flop: Ah 7c 2d"]);
+        assert_eq!(
+            wrap("Player5 wins 120€ with two pair", 14),
+            vec!["Player5 wins", "  120€ with", "  two pair"]
+        );
+        assert_eq!(wrap("Abcdefghij", 4), vec!["Abcd", "  ef", "  gh", "  ij"]);
+        assert_eq!(wrap("", 10), vec![""]);
+    }
+
+    #[test]
+    fn scroll_keeps_the_view() {
+        let mut log = ActionLog::new(Rect::new(0, 0, 300, 2 * PADDING as u32 + 3 * LINE_H as u32));
+        for k in 0..5 {
+            log.push(&format!("Entry {k}"));
+        }
+        assert_eq!(log.visible_lines(), ["Entry 2", "Entry 3", "Entry 4"]);
+
+        log.scroll_by(10);
+        assert_eq!(log.visible_lines(), ["Entry 0", "Entry 1", "Entry 2"]);
+
+        log.push("Entry 5");
+        assert_eq!(log.visible_lines(), ["Entry 0", "Entry 1", "Entry 2"]);
+
+        log.scroll_by(-10);
+        assert_eq!(log.visible_lines(), ["Entry 3", "Entry 4", "Entry 5"]);
+    }
+}
@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::Path;
+
+use sdl2::{pixels::Color, rect::Rect};
+use serde::{Deserialize, Serialize};
+
+use crate::game::setup::Difficulty;
+
+use super::SDL2Graphics;
+
+///Folder with the pictures that can be chosen in the setup screen
+pub const AVATARS_DIR: &str = "assets/avatars";
+const IMAGE_EXTENSIONS: [&str; 3] = ["png", "jpg", "jpeg"];
+
+const SKINS: [(u8, u8, u8); 4] = [
+    (255, 220, 180),
+    (230, 180, 140),
+    (190, 130, 90),
+    (130, 90, 60),
+];
+const HAIRS: [(u8, u8, u8); 5] = [
+    (40, 30, 20),
+    (120, 70, 30),
+    (230, 200, 110),
+    (180, 180, 180),
+    (200, 80, 30),
+];
+
+///The picture of a seat
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Avatar {
+    ///A picture file, the generated face is drawn if it can't be loaded
+    Image(String),
+    ///A face with the colours of the bot, the people have none
+    Generated { bot: Option<Difficulty>, seed: u64 },
+}
+
+impl Default for Avatar {
+    fn default() -> Self {
+        Avatar::Generated { bot: None, seed: 0 }
+    }
+}
+
+impl Avatar {
+    ///The face of the player with the name, the same in every game
+    pub fn generated(bot: Option<Difficulty>, name: &str) -> Self {
+        Avatar::Generated {
+            bot,
+            seed: seed(name),
+        }
+    }
+
+    ///The picture if there's one, else the generated face
+    pub fn or_generated(image: Option<&str>, bot: Option<Difficulty>, name: &str) -> Self {
+        match image {
+            Some(path) => Avatar::Image(path.to_string()),
+            None => Avatar::generated(bot, name),
+        }
+    }
+}
+
+///A stable hash of the name, FNV-1a
+fn seed(name: &str) -> u64 {
+    name.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+///The colours and the expression of a generated avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Face {
+    pub background: Color,
+    pub skin: Color,
+    pub hair: Color,
+    ///The hard bots frown, the rest smile
+    pub frown: bool,
+}
+
+impl Face {
+    ///The easy bots are green and yellow, the hard ones red and purple and the people blue
+    pub fn new(bot: Option<Difficulty>, seed: u64) -> Self {
+        let backgrounds: [(u8, u8, u8); 3] = match bot {
+            None => [(70, 110, 200), (60, 150, 190), (90, 90, 170)],
+            Some(Difficulty::Easy) => [(90, 180, 90), (200, 190, 80), (110, 200, 160)],
+            Some(Difficulty::Hard) => [(170, 50, 50), (120, 50, 150), (150, 40, 100)],
+        };
+        let pick = |n: usize, div: u64| (seed / div % n as u64) as usize;
+
+        Face {
+            background: Color::from(backgrounds[pick(backgrounds.len(), 1)]),
+            skin: Color::from(SKINS[pick(SKINS.len(), 7)]),
+            hair: Color::from(HAIRS[pick(HAIRS.len(), 31)]),
+            frown: bot == Some(Difficulty::Hard),
+        }
+    }
+}
+
+///Draws the picture or the face scaled to the bounds
+pub fn draw_avatar(gfx: &mut SDL2Graphics, avatar: &Avatar, bounds: Rect) -> Result<(), String> {
+    match avatar {
+        Avatar::Image(path) if gfx.draw_image(path, bounds)? => Ok(()),
+        Avatar::Image(_) => draw_face(gfx, Face::new(None, 0), bounds),
+        Avatar::Generated { bot, seed } => draw_face(gfx, Face::new(*bot, *seed), bounds),
+    }
+}
+
+fn draw_face(gfx: &mut SDL2Graphics, face: Face, bounds: Rect) -> Result<(), String> {
+    //The face is drawn in a grid of 10 by 10 cells
+    let s = bounds.width() as f32 / 10.0;
+    let cell = |x: f32, y: f32, w: f32, h: f32| {
+        Rect::new(
+            bounds.x + (x * s) as i32,
+            bounds.y + (y * s) as i32,
+            (w * s).max(1.0) as u32,
+            (h * s).max(1.0) as u32,
+        )
+    };
+    let dark = Color::RGB(30, 30, 30);
+
+    gfx.draw_rect(bounds, face.background)?;
+    gfx.draw_rect(cell(1.5, 8.5, 7.0, 1.5), Color::RGBA(0, 0, 0, 90))?;
+    gfx.draw_rect(cell(2.0, 1.5, 6.0, 2.5), face.hair)?;
+    gfx.draw_rect(cell(2.5, 2.5, 5.0, 5.5), face.skin)?;
+    gfx.draw_rect(cell(3.5, 4.5, 1.0, 1.0), dark)?;
+    gfx.draw_rect(cell(5.5, 4.5, 1.0, 1.0), dark)?;
+
+    if face.frown {
+        gfx.draw_rect(cell(3.2, 3.7, 1.6, 0.4), dark)?;
+        gfx.draw_rect(cell(5.2, 3.7, 1.6, 0.4), dark)?;
+        gfx.draw_rect(cell(4.0, 6.6, 2.0, 0.4), dark)?;
+    } else {
+        gfx.draw_rect(cell(3.5, 6.0, 0.5, 0.5), dark)?;
+        gfx.draw_rect(cell(4.0, 6.5, 2.0, 0.5), dark)?;
+        gfx.draw_rect(cell(6.0, 6.0, 0.5, 0.5), dark)?;
+    }
+
+    Ok(())
+}
+
+///The pictures in the folder, sorted by name
+pub fn images<P: AsRef<Path>>(dir: P) -> Vec<String> {
+    let mut images: Vec<String> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+            })
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+        Err(_) => vec![],
+    };
+    images.sort();
+
+    images
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_faces() {
+        //The same name has the same face in every game
+        assert_eq!(
+            Avatar::generated(Some(Difficulty::Easy), "Player2"),
+            Avatar::generated(Some(Difficulty::Easy), "Player2")
+        );
+        assert_ne!(seed("Player2"), seed("Player3"));
+
+        let easy = Face::new(Some(Difficulty::Easy), 5);
+        let hard = Face::new(Some(Difficulty::Hard), 5);
+        assert!(!easy.frown);
+        assert!(hard.frown);
+        assert_ne!(easy.background, hard.background);
+        assert_eq!(easy.skin, hard.skin);
+
+        assert_eq!(
+            Avatar::or_generated(Some("me.png"), None, "Me"),
+            Avatar::Image("me.png".to_string())
+        );
+        assert_eq!(images("missing-folder"), Vec::<String>::new());
+    }
+}
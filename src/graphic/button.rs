@@ -127,6 +127,14 @@ impl Button {
         self.font_params = f;
     }
 
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
     pub fn set_inactive(&mut self) {
         self.state = ButtonState::Inactive;
     }
@@ -0,0 +1,141 @@
+use sdl2::{
+    pixels::Color,
+    rect::{Point, Rect},
+};
+
+use super::{font::DEFAULT_FONT, SDL2Graphics};
+
+///How many blinds every chip is worth, from the smallest denomination to the biggest
+const CHIP_BLINDS: [i32; 6] = [1, 5, 25, 100, 500, 2500];
+const CHIP_COLORS: [(u8, u8, u8); 6] = [
+    (230, 230, 230),
+    (200, 40, 40),
+    (40, 150, 60),
+    (30, 30, 30),
+    (120, 50, 160),
+    (230, 130, 20),
+];
+
+///More chips of a denomination are drawn as a full column
+pub const MAX_COLUMN: u32 = 10;
+
+const CHIP_W: u32 = 34;
+const CHIP_H: u32 = 10;
+///Vertical distance between two chips of a column
+const CHIP_STEP: i32 = 5;
+
+///The values of the chips, they grow with the blind so a stack has always a few chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChipSet {
+    values: [i32; 6],
+}
+
+impl Default for ChipSet {
+    fn default() -> Self {
+        ChipSet::for_blind(1)
+    }
+}
+
+impl ChipSet {
+    pub fn for_blind(blind: i32) -> Self {
+        ChipSet {
+            values: CHIP_BLINDS.map(|k| k * blind.max(1)),
+        }
+    }
+
+    pub fn values(&self) -> &[i32; 6] {
+        &self.values
+    }
+
+    ///How many chips of every denomination make the amount, using the biggest ones first.
+    ///What's left below the smallest chip counts as one more of them
+    pub fn break_amount(&self, amount: i32) -> [u32; 6] {
+        let mut counts = [0; 6];
+        let mut left = amount.max(0);
+
+        for (count, value) in counts.iter_mut().zip(self.values).rev() {
+            *count = (left / value) as u32;
+            left %= value;
+        }
+        if left > 0 {
+            counts[0] += 1;
+        }
+
+        counts
+    }
+
+    ///Draws the chips of the amount as columns centered on the point, with the amount below
+    pub fn draw(
+        &self,
+        gfx: &mut SDL2Graphics,
+        amount: i32,
+        center: Point,
+        scale: f32,
+        alpha: u8,
+    ) -> Result<(), String> {
+        if amount <= 0 || alpha == 0 {
+            return Ok(());
+        }
+
+        let w = (CHIP_W as f32 * scale).round() as u32;
+        let h = (CHIP_H as f32 * scale).round() as u32;
+        let step = (CHIP_STEP as f32 * scale).round() as i32;
+        if w == 0 || h == 0 {
+            return Ok(());
+        }
+
+        let columns = self
+            .break_amount(amount)
+            .into_iter()
+            .zip(CHIP_COLORS)
+            .filter(|(count, _)| *count > 0)
+            .rev()
+            .collect::<Vec<_>>();
+
+        let gap = w as i32 + 4;
+        let left = center.x - (columns.len() as i32 - 1) * gap / 2;
+        for (k, (count, (r, g, b))) in columns.into_iter().enumerate() {
+            let x = left + k as i32 * gap;
+            for n in 0..count.min(MAX_COLUMN) as i32 {
+                let chip = Rect::from_center(Point::new(x, center.y - n * step), w, h);
+                gfx.draw_rect(chip, Color::RGBA(r / 2, g / 2, b / 2, alpha))?;
+                gfx.draw_rect(
+                    Rect::from_center(chip.center(), w.saturating_sub(6), h.saturating_sub(4)),
+                    Color::RGBA(r, g, b, alpha),
+                )?;
+            }
+        }
+
+        gfx.draw_string(
+            &format!("{amount}€"),
+            DEFAULT_FONT,
+            center.offset(0, h as i32 + 12),
+            true,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn break_amounts() {
+        let chips = ChipSet::for_blind(10);
+        assert_eq!(chips.values(), &[10, 50, 250, 1000, 5000, 25000]);
+
+        assert_eq!(chips.break_amount(0), [0; 6]);
+        assert_eq!(chips.break_amount(1360), [1, 2, 1, 1, 0, 0]);
+        assert_eq!(chips.break_amount(55000), [0, 0, 0, 0, 1, 2]);
+        //The change below the blind is one more small chip
+        assert_eq!(chips.break_amount(65), [2, 1, 0, 0, 0, 0]);
+
+        for amount in [1, 99, 1234, 100000] {
+            let counts = chips.break_amount(amount);
+            let total = counts.iter().zip(chips.values()).map(|(&c, &v)| c as i32 * v).sum::<i32>();
+            assert!(total >= amount && total < amount + chips.values()[0]);
+        }
+    }
+}
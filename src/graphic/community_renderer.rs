@@ -1,53 +1,79 @@
-use sdl2::pixels::Color;
-use sdl2::rect::{Point, Rect};
+use std::time::Duration;
 
-use crate::core::card::Card;
+use sdl2::rect::Point;
 
-use crate::game::game_render::{rect_card_spritesheet, CARD_SPRITE_RATIO};
+use crate::core::card::Card;
 
-use super::font::DEFAULT_FONT;
+use super::chips::ChipSet;
+use super::tween::{deck_point, draw_card, Pose, Tween};
 use super::{ui_component::Drawable, HEIGHT, WIDTH};
 
+const CARD_W: u32 = 100;
+
 #[derive(Default)]
 pub struct CommunityRenderer {
     pub cards: [Option<Card>; 5],
+    ///The cards that are still sliding from the deck
+    tweens: [Option<Tween>; 5],
     pub pot: i32,
+    pub chip_set: ChipSet,
+}
+
+///Where the pot is drawn, the chips are gathered here
+pub fn pot_center() -> Point {
+    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + 100)
+}
+
+fn slot_center(i: usize) -> Point {
+    let w = CARD_W as i32;
+    Point::new(
+        WIDTH as i32 / 2 - 2 * (w + 15) + i as i32 * (w + 15),
+        HEIGHT as i32 / 2 - 40,
+    )
 }
 
 impl Drawable for CommunityRenderer {
     fn draw(&self, gfx: &mut super::SDL2Graphics) -> Result<(), String> {
-        if let Some(tex) = gfx.tex_cache.get("CARD") {
-            let w = 100;
-            let h = (w as f32 * CARD_SPRITE_RATIO) as u32;
-
-            for (i, c) in self.cards.iter().enumerate() {
-                let src = rect_card_spritesheet(*c);
-                let dst = Rect::from_center(
-                    Point::new(
-                        WIDTH as i32 / 2 - 2 * (w + 15) + i as i32 * (w + 15),
-                        HEIGHT as i32 / 2 - 40,
-                    ),
-                    w as u32,
-                    h,
-                );
-                gfx.canvas.copy(tex, src, dst)?;
-            }
+        let size = gfx.card_sheet.size(CARD_W);
+
+        for (i, c) in self.cards.iter().enumerate() {
+            let pose = match &self.tweens[i] {
+                Some(tween) => tween.pose(),
+                None => Pose::at(slot_center(i)),
+            };
+            draw_card(gfx, *c, &pose, size, 0.0)?;
         }
 
-        let pot_center = Point::new(WIDTH as i32/2, HEIGHT as i32/2 + 100);
-        gfx.draw_rect(Rect::from_center(pot_center, 300, 100), Color::RGB(212, 175, 55))?;
-        gfx.draw_string(&format!("{}€",self.pot), DEFAULT_FONT.derive_size(36), pot_center, true)?;
+        self.chip_set.draw(gfx, self.pot, pot_center(), 1.3, 255)?;
 
         Ok(())
     }
 }
 
 impl CommunityRenderer {
-    pub fn add_card(&mut self, card: Card) {
-        for o in self.cards.iter_mut() {
-            if o.is_none() {
-                *o = Some(card);
-                return;
+    ///Slides the card face down from the deck to the first empty slot and turns it over
+    pub fn add_card(&mut self, card: Card, length: Duration) {
+        if let Some(i) = self.cards.iter().position(Option::is_none) {
+            self.cards[i] = Some(card);
+
+            let from = Pose::at(deck_point()).with_scale(0.5).with_opacity(0.0).face_down();
+            self.tweens[i] = Some(Tween::new(from, Pose::at(slot_center(i)), length));
+        }
+    }
+
+    ///Removes the cards of the last hand
+    pub fn clear(&mut self) {
+        self.cards = [None; 5];
+        self.tweens = [None; 5];
+    }
+
+    pub fn update(&mut self, delta: &Duration) {
+        for slot in self.tweens.iter_mut() {
+            if let Some(tween) = slot {
+                tween.update(delta);
+                if tween.is_done() {
+                    *slot = None;
+                }
             }
         }
     }
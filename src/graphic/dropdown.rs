@@ -0,0 +1,114 @@
+use sdl2::{event::Event, mouse::MouseButton, rect::Rect};
+
+use super::{
+    button::ButtonColor,
+    font::{FontParams, DEFAULT_FONT},
+    ui_component::{Drawable, EventReceiver},
+    SDL2Graphics,
+};
+
+///Shows the selected option, clicking on it opens the list of options below
+pub struct Dropdown {
+    bounds: Rect,
+    options: Vec<String>,
+    selected: usize,
+    open: bool,
+    hovered: Option<usize>,
+    color: ButtonColor,
+    font_params: FontParams,
+}
+
+impl EventReceiver<usize> for Dropdown {
+    fn handle_event(&mut self, event: &Event) -> usize {
+        match event {
+            Event::MouseMotion { x, y, .. } => {
+                self.hovered = (0..self.options.len())
+                    .find(|&i| self.option_bounds(i).contains_point((*x, *y)));
+            }
+            Event::MouseButtonDown {
+                mouse_btn: MouseButton::Left,
+                x,
+                y,
+                ..
+            } => {
+                if self.open {
+                    if let Some(i) = (0..self.options.len())
+                        .find(|&i| self.option_bounds(i).contains_point((*x, *y)))
+                    {
+                        self.selected = i;
+                    }
+                    self.open = false;
+                } else {
+                    self.open = self.bounds.contains_point((*x, *y));
+                }
+            }
+            _ => {}
+        }
+
+        self.selected
+    }
+}
+
+impl Drawable for Dropdown {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        gfx.draw_rect(self.bounds, self.color.color)?;
+        gfx.draw_string(
+            &format!("{} v", self.options[self.selected]),
+            self.font_params,
+            self.bounds.center(),
+            true,
+        )?;
+
+        if self.open {
+            for (i, option) in self.options.iter().enumerate() {
+                let color = if self.hovered == Some(i) {
+                    self.color.hover_color
+                } else {
+                    self.color.inactive_color
+                };
+                let bounds = self.option_bounds(i);
+                gfx.draw_rect(bounds, color)?;
+                gfx.draw_string(option, self.font_params, bounds.center(), true)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Dropdown {
+    pub fn new(options: Vec<String>, bounds: Rect, color: ButtonColor) -> Self {
+        Dropdown {
+            bounds,
+            options,
+            selected: 0,
+            open: false,
+            hovered: None,
+            color,
+            font_params: DEFAULT_FONT,
+        }
+    }
+
+    pub fn set_font(&mut self, f: FontParams) {
+        self.font_params = f;
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select(&mut self, i: usize) {
+        self.selected = i.min(self.options.len().saturating_sub(1));
+    }
+
+    ///An open dropdown covers the widgets below it, so it should take the events first
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn option_bounds(&self, i: usize) -> Rect {
+        let mut bounds = self.bounds;
+        bounds.offset(0, (i as i32 + 1) * self.bounds.height() as i32);
+        bounds
+    }
+}
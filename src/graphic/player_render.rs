@@ -1,27 +1,50 @@
+use std::time::Duration;
+
 use sdl2::{
     pixels::Color,
     rect::{Point, Rect},
 };
 
-use crate::game::{player_state::PlayerState, game_render::{rect_card_spritesheet, CARD_SPRITE_RATIO}};
+use crate::game::player_state::PlayerState;
+
+use super::{
+    avatar::draw_avatar,
+    chips::ChipSet,
+    font::DEFAULT_FONT,
+    tween::{deck_point, draw_card, Pose, Tween},
+    ui::{bet_place, hud_bounds, seat_bounds},
+    ui_component::Drawable,
+};
 
-use super::{font::DEFAULT_FONT, ui_component::Drawable};
+const CARD_W: u32 = 52;
+///The cards are centred in a slot of this height, their own height is the one of the theme
+const CARD_SLOT_H: i32 = 76;
 
 pub struct PlayerRenderer {
     bounds: Rect,
     image_bounds: Rect,
+    hud_bounds: Rect,
     state: PlayerState,
+    ///The cards that are being dealt or turned over
+    hand_tweens: [Option<Tween>; 2],
+    chip_set: ChipSet,
+    ///Where the bet is piled
+    bet_place: Point,
 }
 
 impl PlayerRenderer {
-    pub fn new(p: Point, state: PlayerState) -> Self {
-        let bounds = Rect::from_center(p, 260, 140);
+    pub fn new(p: Point, state: PlayerState, chip_set: ChipSet) -> Self {
+        let bounds = seat_bounds(p);
         let image_bounds = Rect::new(bounds.x + 10, bounds.y + 10, 50, 50);
 
         PlayerRenderer {
             bounds,
             image_bounds,
+            hud_bounds: hud_bounds(bounds),
             state,
+            hand_tweens: [None; 2],
+            chip_set,
+            bet_place: bet_place(p),
         }
     }
 
@@ -29,32 +52,107 @@ impl PlayerRenderer {
         self.state = state;
     }
 
+    ///Where the chips of the player come from and go to
+    pub fn center(&self) -> Point {
+        self.bounds.center()
+    }
+
+    ///Slides the cards face down from the deck, the second one after the first
+    pub fn deal(&mut self, length: Duration) {
+        for (k, tween) in self.hand_tweens.iter_mut().enumerate() {
+            let from = Pose::at(deck_point()).with_opacity(0.0).face_down();
+            let to = Pose::at(self.card_center(k)).face_down();
+            *tween = Some(Tween::new(from, to, length).with_wait(length.mul_f32(k as f32 / 2.0)));
+        }
+    }
+
+    ///Turns the cards over when they are shown
+    pub fn flip(&mut self, length: Duration) {
+        for (k, tween) in self.hand_tweens.iter_mut().enumerate() {
+            let to = Pose::at(self.card_center(k));
+            *tween = Some(Tween::new(to.face_down(), to, length));
+        }
+    }
+
+    pub fn update(&mut self, delta: &Duration) {
+        for slot in self.hand_tweens.iter_mut() {
+            if let Some(tween) = slot {
+                tween.update(delta);
+                if tween.is_done() {
+                    *slot = None;
+                }
+            }
+        }
+    }
+
+    ///The first card is on the right corner of the box
+    fn card_center(&self, k: usize) -> Point {
+        let w = CARD_W as i32;
+        self.bounds
+            .bottom_right()
+            .offset(-w / 2 - 10 - k as i32 * (w + 10), -CARD_SLOT_H / 2 - 10)
+    }
+
+    ///Draws the statistics of the player next to the box, once the player has some hands played
+    fn draw_hud(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
+        let stats = self.state.stats;
+        if stats.hands == 0 {
+            return Ok(());
+        }
+
+        let hud = self.hud_bounds;
+        gfx.draw_rect(hud, Color::RGBA(0, 0, 0, 150))?;
+
+        gfx.draw_string(
+            &format!(
+                "VPIP {:.0} OR {:.0} AF {:.1}",
+                stats.vpip() * 100.0,
+                stats.open_raise() * 100.0,
+                stats.aggression_factor()
+            ),
+            DEFAULT_FONT,
+            Point::new(hud.x + 5, hud.y + 3),
+            false,
+        )?;
+
+        gfx.draw_string(
+            &format!(
+                "RR {:.0} FCB {:.0} SD {:.0}",
+                stats.reraise() * 100.0,
+                stats.fold_to_cbet() * 100.0,
+                stats.wtsd() * 100.0
+            ),
+            DEFAULT_FONT,
+            Point::new(hud.x + 5, hud.y + 28),
+            false,
+        )?;
+
+        Ok(())
+    }
+
     fn draw_hand(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
-        Ok(if let Some(tex) = gfx.tex_cache.get("CARD") {
-            let w = 52;
-            let h = (w as f32 * CARD_SPRITE_RATIO) as i32;
-
-            let p = self.bounds.bottom_right().offset(-w - 10, -h - 10);
-            gfx.canvas.copy(
-                tex,
-                rect_card_spritesheet(self.state.hand.map(|hand| hand.0)),
-                Rect::new(p.x, p.y, w as u32, h as u32),
-            )?;
-
-            let p = p.offset(-w - 10, 0);
-            gfx.canvas.copy(
-                tex,
-                rect_card_spritesheet(self.state.hand.map(|hand| hand.1)),
-                Rect::new(p.x, p.y, w as u32, h as u32),
-            )?;
-        })
+        let cards = [
+            self.state.hand.map(|hand| hand.0),
+            self.state.hand.map(|hand| hand.1),
+        ];
+        let size = gfx.card_sheet.size(CARD_W);
+
+        for (k, card) in cards.into_iter().enumerate() {
+            let pose = match &self.hand_tweens[k] {
+                Some(tween) => tween.pose(),
+                None => Pose::at(self.card_center(k)),
+            };
+            draw_card(gfx, card, &pose, size, 0.0)?;
+        }
+
+        Ok(())
     }
 }
 
 impl Drawable for PlayerRenderer {
     fn draw(&self, gfx: &mut super::SDL2Graphics) -> Result<(), String> {
         gfx.draw_rect(self.bounds, Color::GRAY)?;
-        gfx.draw_rect(self.image_bounds, Color::MAGENTA)?;
+        draw_avatar(gfx, &self.state.avatar, self.image_bounds)?;
 
         gfx.draw_string(
             &self.state.name,
@@ -70,14 +168,11 @@ impl Drawable for PlayerRenderer {
             false,
         )?;
 
-        gfx.draw_string(
-            &format!("{}€", self.state.bet),
-            DEFAULT_FONT.derive_color(Color::GREEN),
-            Point::new(self.image_bounds.x, self.image_bounds.bottom() + 40),
-            false,
-        )?;
+        self.chip_set.draw(gfx, self.state.bet, self.bet_place, 1.0, 255)?;
 
         self.draw_hand(gfx)?;
+        self.draw_hud(gfx)?;
+        draw_countdown(gfx, &self.state, self.image_bounds.center(), 40)?;
 
         if self.state.folded {
             gfx.draw_rect(self.bounds, Color::RGBA(0, 0, 0, 180))?;
@@ -88,3 +183,27 @@ impl Drawable for PlayerRenderer {
         Ok(())
     }
 }
+
+///Draws a ring around the point with the time the player has left, from green to red
+pub fn draw_countdown(
+    gfx: &mut super::SDL2Graphics<'_>,
+    state: &PlayerState,
+    center: Point,
+    radius: i32,
+) -> Result<(), String> {
+    if let Some(left) = state.time_left {
+        if !state.turn || state.time_total.is_zero() {
+            return Ok(());
+        }
+
+        let fraction = left.as_secs_f32() / state.time_total.as_secs_f32();
+        let color = Color::RGB(
+            (255.0 * (1.0 - fraction)) as u8,
+            (255.0 * fraction) as u8,
+            0,
+        );
+        gfx.draw_ring(center, radius, fraction, color)?;
+    }
+
+    Ok(())
+}
@@ -3,9 +3,21 @@ use sdl2::{
     rect::{Point, Rect},
 };
 
-use crate::game::{self_controller::SelfController, game_render::{CARD_SPRITE_RATIO, rect_card_spritesheet}};
+use crate::{
+    core::draw::DrawKind,
+    game::self_controller::{PreAction, SelfController},
+};
 
-use super::{button::ButtonColor, font::DEFAULT_FONT, ui_component::Drawable, WIDTH};
+use super::{
+    avatar::draw_avatar,
+    button::ButtonColor,
+    font::DEFAULT_FONT,
+    player_render::draw_countdown,
+    tween::{draw_card, Pose},
+    ui::{bet_place, my_place},
+    ui_component::Drawable,
+    WIDTH,
+};
 
 pub const RAISE_COLOR: ButtonColor = ButtonColor {
     color: Color::RGB(76, 189, 45),
@@ -28,6 +40,13 @@ pub const FOLD_COLOR: ButtonColor = ButtonColor {
     inactive_color: Color::RGB(128, 115, 115),
 };
 
+pub const PRESET_COLOR: ButtonColor = ButtonColor {
+    color: Color::RGB(42, 92, 189),
+    hover_color: Color::RGB(28, 61, 128),
+    pressed_color: Color::RGB(17, 37, 77),
+    inactive_color: Color::RGB(102, 110, 128),
+};
+
 impl Drawable for SelfController {
     fn draw(&self, gfx: &mut super::SDL2Graphics) -> Result<(), String> {
         gfx.draw_rect(self.bounds, Color::RGBA(50, 54, 49, 150))?;
@@ -39,8 +58,17 @@ impl Drawable for SelfController {
         self.call_btn.draw(gfx)?;
         self.fold_btn.draw(gfx)?;
         self.slider.draw(gfx)?;
+        self.raise_input.draw(gfx)?;
+        if self.state.turn {
+            for btn in &self.preset_btns {
+                btn.draw(gfx)?;
+            }
+        } else if !self.state.folded {
+            self.draw_pre_actions(gfx)?;
+        }
 
-        gfx.draw_rect(self.image_bounds, Color::MAGENTA)?;
+        draw_avatar(gfx, &self.state.avatar, self.image_bounds)?;
+        draw_countdown(gfx, &self.state, self.image_bounds.center(), 90)?;
 
         if !self.state.name.is_empty() {
             gfx.draw_string(
@@ -80,25 +108,80 @@ impl Drawable for SelfController {
         }
 
         self.draw_hand(gfx)?;
+        self.draw_advice(gfx)?;
+        self.chip_set
+            .draw(gfx, self.state.bet, bet_place(my_place()), 1.0, 255)?;
 
         Ok(())
     }
 }
 
 impl SelfController {
-    fn draw_hand(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
-        if let Some(hand) = self.state.hand {
-            if let Some(tex) = gfx.tex_cache.get("CARD") {
-                let w = 180;
-                let h = (w as f32 * CARD_SPRITE_RATIO) as u32;
+    ///The toggles played when the turn comes, the chosen one is marked
+    fn draw_pre_actions(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
+        for btn in &self.pre_btns {
+            btn.draw(gfx)?;
+        }
+
+        let chosen = self.pre_action.map(|pre| match pre {
+            PreAction::CheckFold => 0,
+            PreAction::Check => 1,
+            PreAction::CallAny => 2,
+            PreAction::Call(_) => 3,
+        });
+        if let Some(btn) = chosen.and_then(|k| self.pre_btns.get(k)) {
+            gfx.draw_rect(btn.bounds(), Color::RGBA(255, 255, 0, 100))?;
+        }
 
-                let c1 = rect_card_spritesheet(Some(hand.0));
-                let d1 = Rect::from_center(self.bounds.center().offset(-70, -30), w, h);
-                gfx.canvas.copy_ex(tex, c1, d1, -5.0, None, false, false)?;
+        Ok(())
+    }
+
+    ///The training overlay over the left corner of the box
+    fn draw_advice(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
+        let Some(advice) = &self.advice else {
+            return Ok(());
+        };
+
+        let panel = Rect::new(20, self.bounds.y - 160, 270, 150);
+        gfx.draw_rect(panel, Color::RGBA(0, 0, 0, 180))?;
+
+        let draw = match advice.draw {
+            DrawKind::None => "No draw".to_string(),
+            kind => format!("{kind:?} draw, {} outs", advice.outs),
+        };
+        let lines = [
+            format!("{:?}", advice.made),
+            draw,
+            format!(
+                "Equity {:.0}% vs {}",
+                advice.equity * 100.0,
+                advice.opponents
+            ),
+            format!("Pot odds {:.0}%", advice.pot_odds * 100.0),
+            format!("Suggested: {}", advice.suggestion.name()),
+        ];
+        for (k, line) in lines.iter().enumerate() {
+            gfx.draw_string(
+                line,
+                DEFAULT_FONT,
+                Point::new(panel.x + 10, panel.y + 8 + 27 * k as i32),
+                false,
+            )?;
+        }
+
+        Ok(())
+    }
 
-                let c2 = rect_card_spritesheet(Some(hand.1));
-                let d2 = Rect::from_center(self.bounds.center().offset(70, -30), w, h);
-                gfx.canvas.copy_ex(tex, c2, d2, 5.0, None, false, false)?;
+    fn draw_hand(&self, gfx: &mut super::SDL2Graphics<'_>) -> Result<(), String> {
+        if let Some(hand) = self.state.hand {
+            let size = gfx.card_sheet.size(180);
+
+            for (k, (card, angle)) in [(hand.0, -5.0), (hand.1, 5.0)].into_iter().enumerate() {
+                let pose = match &self.hand_tweens[k] {
+                    Some(tween) => tween.pose(),
+                    None => Pose::at(self.card_center(k)),
+                };
+                draw_card(gfx, Some(card), &pose, size, angle)?;
             }
         }
 
@@ -92,6 +92,10 @@ impl Slider {
         self.current
     }
 
+    pub fn set_value(&mut self, value: f32) {
+        self.current = value.clamp(0.0, 1.0);
+    }
+
     fn calc_value(&mut self, x: i32) {
         self.current = (x - self.slide_bounds.x) as f32 / self.slide_bounds.width() as f32;
         self.current = f32::max(0.0, self.current);
@@ -0,0 +1,119 @@
+use sdl2::{
+    event::Event,
+    keyboard::Keycode,
+    mouse::MouseButton,
+    pixels::Color,
+    rect::{Point, Rect},
+};
+
+use super::{
+    font::{FontParams, DEFAULT_FONT},
+    ui_component::{Drawable, EventReceiver},
+    SDL2Graphics,
+};
+
+///A box where text is typed after clicking on it
+pub struct TextInput {
+    bounds: Rect,
+    text: String,
+    max_len: usize,
+    ///Only digits can be typed
+    numeric: bool,
+    focused: bool,
+    color: Color,
+    focus_color: Color,
+    font_params: FontParams,
+}
+
+impl EventReceiver<bool> for TextInput {
+    ///Returns if the text has changed
+    fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::MouseButtonDown {
+                mouse_btn, x, y, ..
+            } => {
+                if *mouse_btn == MouseButton::Left {
+                    self.focused = self.bounds.contains_point((*x, *y));
+                }
+                false
+            }
+            Event::TextInput { text, .. } if self.focused => {
+                let len = self.text.chars().count();
+                let typed = text
+                    .chars()
+                    .filter(|c| !c.is_control() && (!self.numeric || c.is_ascii_digit()))
+                    .take(self.max_len.saturating_sub(len))
+                    .collect::<String>();
+                self.text.push_str(&typed);
+                !typed.is_empty()
+            }
+            Event::KeyDown {
+                keycode: Some(Keycode::Backspace),
+                ..
+            } if self.focused => self.text.pop().is_some(),
+            _ => false,
+        }
+    }
+}
+
+impl Drawable for TextInput {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        let color = if self.focused {
+            self.focus_color
+        } else {
+            self.color
+        };
+        gfx.draw_rect(self.bounds, color)?;
+
+        let txt = if self.focused {
+            format!("{}_", self.text)
+        } else {
+            self.text.clone()
+        };
+        let p = Point::new(
+            self.bounds.x + 20,
+            self.bounds.center().y - self.font_params.size as i32 / 2,
+        );
+        gfx.draw_string(&txt, self.font_params, p, false)?;
+
+        Ok(())
+    }
+}
+
+impl TextInput {
+    pub fn new(text: String, bounds: Rect, color: Color, focus_color: Color) -> Self {
+        TextInput {
+            bounds,
+            text,
+            max_len: 16,
+            numeric: false,
+            focused: false,
+            color,
+            focus_color,
+            font_params: DEFAULT_FONT,
+        }
+    }
+
+    ///Only digits can be typed, up to 9 so the number fits in an i32
+    pub fn numeric(mut self) -> Self {
+        self.numeric = true;
+        self.max_len = 9;
+        self
+    }
+
+    pub fn set_font(&mut self, f: FontParams) {
+        self.font_params = f;
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+}
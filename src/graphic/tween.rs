@@ -0,0 +1,236 @@
+use std::time::Duration;
+
+use sdl2::rect::{Point, Rect};
+
+use crate::core::card::Card;
+
+use super::{chips::ChipSet, ui_component::Drawable, SDL2Graphics, HEIGHT, WIDTH};
+
+///Where the cards are dealt from, the center of the table
+pub fn deck_point() -> Point {
+    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2)
+}
+
+///How the progress of a tween speeds up and slows down
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Easing {
+    Linear,
+    ///Fast at the start, slow at the end
+    #[default]
+    EaseOut,
+    ///Slow at the start and at the end
+    EaseInOut,
+}
+
+impl Easing {
+    ///The eased progress for a progress from 0 to 1
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+///The animated properties of a sprite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose {
+    pub x: f32,
+    pub y: f32,
+    pub scale: f32,
+    ///From transparent at 0 to opaque at 1
+    pub opacity: f32,
+    ///The back of a card is seen at 0 and the face at 1
+    pub flip: f32,
+}
+
+impl Pose {
+    ///An opaque sprite at its normal size, showing the face
+    pub fn at(p: Point) -> Self {
+        Pose {
+            x: p.x as f32,
+            y: p.y as f32,
+            scale: 1.0,
+            opacity: 1.0,
+            flip: 1.0,
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    pub fn face_down(mut self) -> Self {
+        self.flip = 0.0;
+        self
+    }
+
+    pub fn point(&self) -> Point {
+        Point::new(self.x.round() as i32, self.y.round() as i32)
+    }
+
+    fn lerp(&self, to: &Pose, t: f32) -> Pose {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Pose {
+            x: mix(self.x, to.x),
+            y: mix(self.y, to.y),
+            scale: mix(self.scale, to.scale),
+            opacity: mix(self.opacity, to.opacity),
+            flip: mix(self.flip, to.flip),
+        }
+    }
+}
+
+///Moves a sprite from a pose to another one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    from: Pose,
+    to: Pose,
+    ///Time before the tween starts moving
+    wait: Duration,
+    elapsed: Duration,
+    length: Duration,
+    easing: Easing,
+}
+
+impl Tween {
+    pub fn new(from: Pose, to: Pose, length: Duration) -> Self {
+        Tween {
+            from,
+            to,
+            wait: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            length,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn with_wait(mut self, wait: Duration) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn update(&mut self, delta: &Duration) {
+        let moving = delta.saturating_sub(self.wait);
+        self.wait = self.wait.saturating_sub(*delta);
+        self.elapsed = (self.elapsed + moving).min(self.length);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.wait.is_zero() && self.elapsed >= self.length
+    }
+
+    pub fn pose(&self) -> Pose {
+        if self.length.is_zero() {
+            return self.to;
+        }
+
+        let t = self.elapsed.as_secs_f32() / self.length.as_secs_f32();
+        self.from.lerp(&self.to, self.easing.apply(t))
+    }
+}
+
+///A chip stack on its way between a seat and the pot
+pub struct ChipSprite {
+    pub amount: i32,
+    pub chip_set: ChipSet,
+    pub tween: Tween,
+}
+
+impl Drawable for ChipSprite {
+    fn draw(&self, gfx: &mut SDL2Graphics) -> Result<(), String> {
+        let pose = self.tween.pose();
+        let alpha = (255.0 * pose.opacity.clamp(0.0, 1.0)) as u8;
+        self.chip_set
+            .draw(gfx, self.amount, pose.point(), pose.scale, alpha)
+    }
+}
+
+///Draws a card of the given size with its pose, the card narrows to the edge and widens with the
+///other side while it's flipped
+pub fn draw_card(
+    gfx: &mut SDL2Graphics,
+    card: Option<Card>,
+    pose: &Pose,
+    (w, h): (u32, u32),
+    angle: f64,
+) -> Result<(), String> {
+    let (card, narrow) = if pose.flip < 0.5 {
+        (None, 1.0 - 2.0 * pose.flip)
+    } else {
+        (card, 2.0 * pose.flip - 1.0)
+    };
+
+    let w = (w as f32 * pose.scale * narrow).round() as u32;
+    let h = (h as f32 * pose.scale).round() as u32;
+    if w == 0 || h == 0 || pose.opacity <= 0.0 {
+        return Ok(());
+    }
+
+    let src = gfx.card_sheet.rect(card, gfx.card_back);
+    if let Some(tex) = gfx.tex_cache.get_mut("CARD") {
+        tex.set_alpha_mod((255.0 * pose.opacity.clamp(0.0, 1.0)) as u8);
+        let dst = Rect::from_center(pose.point(), w, h);
+        let drawn = gfx.canvas.copy_ex(tex, src, dst, angle, None, false, false);
+        tex.set_alpha_mod(255);
+        drawn?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_ends() {
+        for easing in [Easing::Linear, Easing::EaseOut, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+    }
+
+    #[test]
+    fn tween_moves_after_the_wait() {
+        let from = Pose::at(Point::new(0, 0)).face_down();
+        let to = Pose::at(Point::new(100, 50));
+        let mut tween = Tween::new(from, to, Duration::from_millis(100))
+            .with_wait(Duration::from_millis(50))
+            .with_easing(Easing::Linear);
+
+        tween.update(&Duration::from_millis(30));
+        assert_eq!(tween.pose(), from);
+
+        tween.update(&Duration::from_millis(70));
+        assert_eq!(tween.pose().point(), Point::new(50, 25));
+        assert_eq!(tween.pose().flip, 0.5);
+        assert!(!tween.is_done());
+
+        tween.update(&Duration::from_millis(100));
+        assert_eq!(tween.pose(), to);
+        assert!(tween.is_done());
+    }
+}
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
-use sdl2::{event::Event, rect::Point};
+use sdl2::{
+    event::Event,
+    rect::{Point, Rect},
+};
 
 use crate::{
     core::error::EngineError,
@@ -11,21 +14,43 @@ use crate::{
 };
 
 use super::{
-    community_renderer::CommunityRenderer,
+    action_log::ActionLog,
+    chips::ChipSet,
+    community_renderer::{pot_center, CommunityRenderer},
     player_render::PlayerRenderer,
+    tween::{ChipSprite, Pose, Tween},
     ui_component::{Drawable, EventReceiver},
-    SDL2Graphics,
+    SDL2Graphics, HEIGHT, WIDTH,
 };
 
+///From heads-up to 10-max
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 10;
+
+///Radii of the ellipse around the table where the players are seated
+const TABLE_RX: f32 = 560.0;
+const TABLE_RY: f32 = 290.0;
+///How far from the center of the table the bets are piled, the seats are at 1
+const BET_DISTANCE: f32 = 0.7;
+
 #[derive(Default)]
 pub struct UI {
     pub player_controller: SelfController,
     pub players: HashMap<usize, PlayerRenderer>,
     pub community: CommunityRenderer,
+    ///The chips moving between the seats and the pot
+    pub chips: Vec<ChipSprite>,
+    ///Every action of the current and the previous hands
+    pub log: ActionLog,
 }
 
 impl EventReceiver<Result<Option<PlayerAction>, String>> for UI {
     fn handle_event(&mut self, event: &Event) -> Result<Option<PlayerAction>, String> {
+        //The wheel over the log scrolls it instead of changing the raise
+        if self.log.handle_event(event) {
+            return Ok(None);
+        }
+
         Ok(self.player_controller.handle_event(event))
     }
 }
@@ -39,6 +64,11 @@ impl Drawable for UI {
         }
 
         self.community.draw(gfx)?;
+        self.log.draw(gfx)?;
+
+        for c in &self.chips {
+            c.draw(gfx)?;
+        }
 
         Ok(())
     }
@@ -49,33 +79,96 @@ impl UI {
         &mut self,
         player_states: &[PlayerState],
         myself: usize,
+        blind: i32,
     ) -> Result<(), EngineError> {
-        let mut places = vec![
-            Point::new(500, 770),
-            Point::new(400, 540),
-            Point::new(500, 300),
-            Point::new(960, 250),
-            Point::new(1420, 300),
-            Point::new(1520, 540),
-            Point::new(1420, 770),
-        ];
-
-        for i in (0..myself).rev() {
-            let place = places.remove(0);
-            let v = PlayerRenderer::new(place, player_states[i].clone());
-            self.players.insert(i, v);
+        let n = player_states.len();
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&n) || myself >= n {
+            return Err(EngineError::BadGameError);
         }
-        for i in (myself+1)..player_states.len() {
-            let place = places.remove(places.len()-1);
-            let v = PlayerRenderer::new(place, player_states[i].clone());
+
+        let chip_set = ChipSet::for_blind(blind);
+        for (i, place) in seat_places(n, myself) {
+            let v = PlayerRenderer::new(place, player_states[i].clone(), chip_set);
             self.players.insert(i, v);
         }
 
         self.player_controller.set_state(player_states[myself].clone());
+        self.player_controller.chip_set = chip_set;
+        self.player_controller.blind = blind;
+        self.community.chip_set = chip_set;
 
         Ok(())
     }
 
+    ///Moves the animations forward, their lengths are already scaled by the animation speed
+    pub fn update(&mut self, delta: &Duration) {
+        self.player_controller.update(delta);
+        for p in self.players.values_mut() {
+            p.update(delta);
+        }
+        self.community.update(delta);
+
+        for c in self.chips.iter_mut() {
+            c.tween.update(delta);
+        }
+        self.chips.retain(|c| !c.tween.is_done());
+    }
+
+    ///Where the player with the index is seated
+    pub fn seat_center(&self, i: usize) -> Point {
+        match self.players.get(&i) {
+            Some(p) => p.center(),
+            None => self.player_controller.image_bounds.center(),
+        }
+    }
+
+    ///Slides the cards of the player from the deck
+    pub fn deal(&mut self, i: usize, length: Duration) {
+        match self.players.get_mut(&i) {
+            Some(p) => p.deal(length),
+            None => self.player_controller.deal(length),
+        }
+    }
+
+    ///Turns over the cards of an opponent at the showdown
+    pub fn flip(&mut self, i: usize, length: Duration) {
+        if let Some(p) = self.players.get_mut(&i) {
+            p.flip(length);
+        }
+    }
+
+    ///Moves the chips of a bet from the seat to its pile in front
+    pub fn bet_chips(&mut self, i: usize, amount: i32, length: Duration) {
+        let seat = self.seat_center(i);
+        let place = match self.players.get(&i) {
+            Some(_) => seat,
+            None => my_place(),
+        };
+        self.move_chips(seat, bet_place(place), amount, length);
+    }
+
+    ///Moves the pot to the seat of the winner
+    pub fn win_chips(&mut self, i: usize, amount: i32, length: Duration) {
+        self.move_chips(pot_center(), self.seat_center(i), amount, length);
+    }
+
+    fn move_chips(&mut self, from: Point, to: Point, amount: i32, length: Duration) {
+        let tween = Tween::new(Pose::at(from), Pose::at(to).with_opacity(0.0), length);
+        let chip_set = self.community.chip_set;
+        self.chips.push(ChipSprite {
+            amount,
+            chip_set,
+            tween,
+        });
+    }
+
+    ///Clears the table for the next hand
+    pub fn new_hand(&mut self) {
+        self.community.clear();
+        self.chips.clear();
+        self.player_controller.pre_action = None;
+    }
+
     pub fn update_states(&mut self, player_states: &[PlayerState], myself: usize) {
         for (i, p) in player_states.iter().enumerate() {
             if i == myself {
@@ -86,3 +179,100 @@ impl UI {
         }
     }
 }
+
+///Where the opponents are seated, evenly around the table clockwise from the local player,
+///who is always at the bottom
+pub fn seat_places(n: usize, myself: usize) -> Vec<(usize, Point)> {
+    let center = Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2);
+
+    (0..n)
+        .filter(|&i| i != myself)
+        .map(|i| {
+            let k = (myself + n - i) % n;
+            let angle = k as f32 / n as f32 * std::f32::consts::TAU;
+            let place = center.offset(
+                -(TABLE_RX * angle.sin()).round() as i32,
+                (TABLE_RY * angle.cos()).round() as i32,
+            );
+            (i, place)
+        })
+        .collect()
+}
+
+///The box of an opponent seated in the place
+pub fn seat_bounds(place: Point) -> Rect {
+    Rect::from_center(place, 260, 140)
+}
+
+///Where the statistics of a seat are shown, over the box at the top of the table and on the outer
+///side of the box everywhere else, so they don't cover the seats above
+pub fn hud_bounds(seat: Rect) -> Rect {
+    let center = Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2);
+    let p = seat.center();
+    let (w, h) = (seat.width(), 55);
+
+    if p.y < center.y - TABLE_RY as i32 / 2 || p.x == center.x {
+        Rect::new(seat.x, seat.y - h as i32 - 5, w, h)
+    } else if p.x < center.x {
+        Rect::new(seat.x - w as i32 - 5, seat.y, w, h)
+    } else {
+        Rect::new(seat.right() + 5, seat.y, w, h)
+    }
+}
+
+///The seat of the local player, at the bottom of the ellipse
+pub fn my_place() -> Point {
+    Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 + TABLE_RY as i32)
+}
+
+///Where the bets of a seat are piled, between the seat and the center of the table
+pub fn bet_place(seat: Point) -> Point {
+    let center = Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2);
+    let offset = seat - center;
+    center.offset(
+        (offset.x as f32 * BET_DISTANCE).round() as i32,
+        (offset.y as f32 * BET_DISTANCE).round() as i32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn seats_for_every_table_size() {
+        let controller = SelfController::default().bounds;
+
+        for n in MIN_PLAYERS..=MAX_PLAYERS {
+            for myself in 0..n {
+                let places = seat_places(n, myself);
+                assert_eq!(places.len(), n - 1);
+                assert!(places.iter().all(|&(i, _)| i != myself));
+                assert!(places.iter().map(|&(_, p)| p).all_unique());
+
+                //The box and the statistics of every seat
+                let rects: Vec<_> = places
+                    .iter()
+                    .map(|&(_, p)| {
+                        let bounds = seat_bounds(p);
+                        [bounds, hud_bounds(bounds)]
+                    })
+                    .collect();
+                for (k, seat) in rects.iter().enumerate() {
+                    for &r in seat {
+                        assert!(Rect::new(0, 0, WIDTH, HEIGHT).contains_rect(r));
+                        assert!(!r.has_intersection(controller));
+                    }
+                    for other in &rects[k + 1..] {
+                        assert!(seat.iter().all(|a| other.iter().all(|b| !a.has_intersection(*b))));
+                    }
+                }
+            }
+        }
+
+        //Heads-up the opponent sits in front
+        assert_eq!(seat_places(2, 0)[0].1, Point::new(WIDTH as i32 / 2, HEIGHT as i32 / 2 - 290));
+    }
+}
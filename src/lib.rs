@@ -4,6 +4,12 @@ pub mod graphic;
 
 pub mod music;
 
+pub mod net;
+
 pub mod game;
 
+pub mod settings;
+
+pub mod theme;
+
 extern crate sdl2;
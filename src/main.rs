@@ -1,16 +1,26 @@
 extern crate sdl2;
 
-use std::path::Path;
 use std::time::{Duration, Instant};
 
+use log::warn;
+
+use poker::game::menu::{Menu, MenuChoice};
+use poker::game::profile::{Profile, PROFILE_PATH};
+use poker::game::save::SAVE_PATH;
 use poker::game::{Game, DEBUG};
 use poker::graphic;
+use poker::graphic::WindowMode;
 use poker::graphic::font::DEFAULT_FONT;
 use poker::graphic::ui_component::{Drawable, EventReceiver};
+use poker::music::{Audio, Playlist};
+use poker::settings::{Settings, SETTINGS_PATH};
+use poker::theme::{Theme, THEMES_DIR};
 
 use sdl2::event::Event;
 use sdl2::keyboard::Keycode;
 use sdl2::rect::Point;
+use sdl2::render::TextureCreator;
+use sdl2::video::WindowContext;
 
 fn main() {
     env_logger::init();
@@ -23,14 +33,25 @@ fn main() {
     let _image_context = sdl2::image::init(sdl2::image::InitFlag::PNG)
         .expect("Couldn't initialize the image context");
 
-    let font_path = Path::new("assets/RetroGaming.ttf");
+    let mut settings = Settings::load_or_default(SETTINGS_PATH);
 
-    let window = video_subsystem
-        .window(graphic::TITLE, graphic::WIDTH, graphic::HEIGHT)
-        .fullscreen()
-        .position_centered()
-        .build()
-        .expect("Couldn't create window");
+    //poker --windowed | --borderless [--resolution <width>x<height>] changes the saved window
+    let args: Vec<String> = std::env::args().collect();
+    settings.display = settings.display.with_args(&args);
+    let display = settings.display;
+
+    let mut window = video_subsystem.window(graphic::TITLE, display.width, display.height);
+    window.position_centered().resizable();
+    match display.mode {
+        WindowMode::Windowed => (),
+        WindowMode::Fullscreen => {
+            window.fullscreen();
+        }
+        WindowMode::Borderless => {
+            window.fullscreen_desktop();
+        }
+    }
+    let window = window.build().expect("Couldn't create window");
 
     let canvas = window
         .into_canvas()
@@ -40,11 +61,36 @@ fn main() {
 
     //Load textures and construct gfx
     let creator = canvas.texture_creator();
-    let mut gfx = graphic::SDL2Graphics::new(canvas, ttf, font_path, &creator);
+    let mut gfx = graphic::SDL2Graphics::new(canvas, ttf);
+    set_theme(&mut gfx, &creator, &settings);
+    let mut audio = Audio::new(
+        Playlist::new(settings.music.clone(), true),
+        settings.music_volume,
+        settings.effects_volume,
+    );
 
-    let mut game = Game::new(true);
-    game.start();
-    game.draw(&mut gfx).ok();
+    //poker --connect <address> [name] plays in a table server
+    //poker --load <file> continues a saved hand
+    //Without arguments the main menu is shown
+    let arg = |name: &str| args.iter().position(|a| a == name);
+    let mut menu = Menu::new(Profile::load_or_default(PROFILE_PATH), settings.clone());
+    let mut game = match (arg("--connect"), arg("--load")) {
+        (Some(i), _) => {
+            let addr = args.get(i + 1).expect("Missing the address of the table");
+            let name = args.get(i + 2).cloned().unwrap_or("Me".to_string());
+            Some(Game::connect(addr.clone(), name).with_settings(settings.clone()))
+        }
+        (None, Some(i)) => {
+            let path = args.get(i + 1).expect("Missing the saved game");
+            let mut game = Game::load(path)
+                .expect("Couldn't load the saved game")
+                .with_settings(settings.clone())
+                .with_profile(menu.profile().clone());
+            game.start();
+            Some(game)
+        }
+        (None, None) => None,
+    };
 
     let mut time = (0u128, 0i32, 0u128);
     let mut delta = Duration::ZERO;
@@ -56,7 +102,9 @@ fn main() {
     'running: loop {
         let t = Instant::now();
 
-        game.early_update();
+        if let Some(game) = &mut game {
+            game.early_update();
+        }
 
         //Event update
         for event in event_pump.poll_iter() {
@@ -68,18 +116,79 @@ fn main() {
                 } => break 'running,
                 _ => {}
             }
-            game.handle_event(&event).ok();
+
+            match &mut game {
+                Some(game) => {
+                    game.handle_event(&event).ok();
+                }
+                None => match menu.handle_event(&event) {
+                    Some(MenuChoice::NewGame(setup)) => {
+                        let mut new_game = Game::new(setup)
+                            .with_seats(&menu.settings().seats)
+                            .with_settings(settings.clone())
+                            .with_profile(menu.profile().clone());
+                        new_game.start();
+                        game = Some(new_game);
+                    }
+                    Some(MenuChoice::Connect { addr, name }) => {
+                        game = Some(Game::connect(addr, name).with_settings(settings.clone()));
+                    }
+                    Some(MenuChoice::Continue) => match Game::load(SAVE_PATH) {
+                        Ok(saved) => {
+                            let mut saved = saved
+                                .with_settings(settings.clone())
+                                .with_profile(menu.profile().clone());
+                            saved.start();
+                            game = Some(saved);
+                        }
+                        Err(e) => warn!("Couldn't load the saved game: {e}"),
+                    },
+                    Some(MenuChoice::Settings(changed)) => {
+                        if changed.display != settings.display {
+                            if let Err(e) = gfx.set_display(changed.display) {
+                                warn!("Couldn't change the window: {e}");
+                            }
+                        }
+                        if (&changed.theme, changed.four_color_deck, changed.card_back)
+                            != (&settings.theme, settings.four_color_deck, settings.card_back)
+                        {
+                            set_theme(&mut gfx, &creator, &changed);
+                        }
+                        audio.set_volumes(changed.music_volume, changed.effects_volume);
+                        settings = changed;
+                    }
+                    Some(MenuChoice::Quit) => break 'running,
+                    None => {}
+                },
+            }
         }
 
-        //Internal structure update
-        game.update(&delta);
+        //The menu is made again to show the saved hand and the profile updated by the game
+        if game.as_ref().is_some_and(Game::has_left) {
+            game = None;
+            menu = Menu::new(Profile::load_or_default(PROFILE_PATH), settings.clone());
+        }
 
-        // Graphic update
-        game.draw(&mut gfx).ok();
-        if DEBUG.load(std::sync::atomic::Ordering::Relaxed) && game.is_running() {
-            draw_time_elapsed(&mut gfx, time);
-            gfx.show();
+        match &mut game {
+            Some(game) => {
+                //Internal structure update
+                game.update(&delta);
+                for sound in game.take_sounds() {
+                    audio.play(sound);
+                }
+
+                // Graphic update
+                game.draw(&mut gfx).ok();
+                if DEBUG.load(std::sync::atomic::Ordering::Relaxed) && game.is_running() {
+                    draw_time_elapsed(&mut gfx, time);
+                    gfx.show();
+                }
+            }
+            None => {
+                menu.draw(&mut gfx).ok();
+            }
         }
+        audio.update(&delta);
 
         time.2 = t.elapsed().as_nanos();
         time.0 = time.0 * time.1 as u128 + time.2;
@@ -89,6 +198,17 @@ fn main() {
     }
 }
 
+fn set_theme<'a>(
+    gfx: &mut graphic::SDL2Graphics<'a>,
+    creator: &'a TextureCreator<WindowContext>,
+    settings: &Settings,
+) {
+    let theme = Theme::find(THEMES_DIR, &settings.theme);
+    if let Err(e) = gfx.set_theme(creator, &theme, settings.four_color_deck, settings.card_back) {
+        warn!("Couldn't load the theme {}: {e}", theme.name);
+    }
+}
+
 fn draw_time_elapsed(gfx: &mut graphic::SDL2Graphics, time: (u128, i32, u128)) {
     let total_avg = 1.max(time.0 / 1000);
     let total = 1.max(time.2 / 1000);
@@ -1,22 +1,332 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+
+///Time the old track and the new one play together
+pub const CROSSFADE: Duration = Duration::from_secs(3);
+///Sounds remembered by the null sink, the older ones are forgotten
+const MAX_PLAYED: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sound {
+    Deal,
+    Chips,
+    Check,
+    Fold,
+    AllIn,
+    Win,
+}
+
+impl Sound {
+    pub const ALL: [Sound; 6] = [
+        Sound::Deal,
+        Sound::Chips,
+        Sound::Check,
+        Sound::Fold,
+        Sound::AllIn,
+        Sound::Win,
+    ];
+
+    pub fn path(&self) -> &'static str {
+        match self {
+            Sound::Deal => "assets/sounds/deal.wav",
+            Sound::Chips => "assets/sounds/chips.wav",
+            Sound::Check => "assets/sounds/check.wav",
+            Sound::Fold => "assets/sounds/fold.wav",
+            Sound::AllIn => "assets/sounds/all_in.wav",
+            Sound::Win => "assets/sounds/win.wav",
+        }
+    }
+}
+
+///The tracks of the background music, played in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Playlist {
+    tracks: Vec<String>,
+    next: usize,
+    ///Starts again after the last track
+    looping: bool,
+}
+
+impl Playlist {
+    pub fn new(tracks: Vec<String>, looping: bool) -> Self {
+        Playlist {
+            tracks,
+            next: 0,
+            looping,
+        }
+    }
+
+    ///The track to play next, None when the playlist has ended
+    pub fn next_track(&mut self) -> Option<&str> {
+        if self.next >= self.tracks.len() {
+            if !self.looping || self.tracks.is_empty() {
+                return None;
+            }
+            self.next = 0;
+        }
+
+        self.next += 1;
+        Some(&self.tracks[self.next - 1])
+    }
+}
+
+///The volumes of the track that goes out and the one that comes in, after some time of the fade
+pub fn crossfade_volumes(elapsed: Duration, length: Duration) -> (f32, f32) {
+    if length.is_zero() {
+        return (0.0, 1.0);
+    }
+
+    let t = (elapsed.as_secs_f32() / length.as_secs_f32()).clamp(0.0, 1.0);
+    (1.0 - t, t)
+}
+
+struct Track {
+    sink: Sink,
+    ///Time until the track ends, unknown for some formats
+    left: Option<Duration>,
+}
+
+///The audio device, there's none in headless machines
+struct Output {
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    effects: HashMap<Sound, Arc<[u8]>>,
+
+    current: Option<Track>,
+    ///The track that is fading out
+    fading: Option<Sink>,
+    fade: Duration,
+}
+
+///Plays the music and the sound effects, without an audio device it's a null sink that only
+///remembers the last sounds
+pub struct Audio {
+    output: Option<Output>,
+    playlist: Playlist,
+    music_volume: f32,
+    effects_volume: f32,
+    ///The last sounds played by the null sink
+    played: Vec<Sound>,
+}
+
+impl Audio {
+    ///Opens the default audio device, falling back to the null sink if there isn't one
+    pub fn new(playlist: Playlist, music_volume: f32, effects_volume: f32) -> Self {
+        let mut audio = Audio::null(playlist, music_volume, effects_volume);
+
+        match OutputStream::try_default() {
+            Ok((stream, handle)) => {
+                let effects = Sound::ALL
+                    .iter()
+                    .filter_map(|&s| match std::fs::read(s.path()) {
+                        Ok(bytes) => Some((s, Arc::<[u8]>::from(bytes))),
+                        Err(e) => {
+                            warn!("Couldn't load the sound {}: {e}", s.path());
+                            None
+                        }
+                    })
+                    .collect();
+
+                audio.output = Some(Output {
+                    _stream: stream,
+                    handle,
+                    effects,
+                    current: None,
+                    fading: None,
+                    fade: Duration::ZERO,
+                });
+                audio.next_track();
+            }
+            Err(e) => warn!("There's no audio device, the game is muted: {e}"),
+        }
+
+        audio
+    }
+
+    ///Plays nothing, used when there's no audio device and in the tests
+    pub fn null(playlist: Playlist, music_volume: f32, effects_volume: f32) -> Self {
+        Audio {
+            output: None,
+            playlist,
+            music_volume: music_volume.clamp(0.0, 1.0),
+            effects_volume: effects_volume.clamp(0.0, 1.0),
+            played: Vec::new(),
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.output.is_none()
+    }
+
+    ///The last sounds played without an audio device, the oldest first
+    pub fn played(&self) -> &[Sound] {
+        &self.played
+    }
+
+    pub fn play(&mut self, sound: Sound) {
+        let Some(output) = &self.output else {
+            if self.played.len() == MAX_PLAYED {
+                self.played.remove(0);
+            }
+            self.played.push(sound);
+            return;
+        };
+
+        let Some(bytes) = output.effects.get(&sound) else {
+            return;
+        };
+        let played = Decoder::new(Cursor::new(bytes.clone()))
+            .map_err(|e| e.to_string())
+            .and_then(|source| {
+                let sink = Sink::try_new(&output.handle).map_err(|e| e.to_string())?;
+                sink.set_volume(self.effects_volume);
+                sink.append(source);
+                sink.detach();
+                Ok(())
+            });
+        if let Err(e) = played {
+            warn!("Couldn't play the sound {}: {e}", sound.path());
+        }
+    }
+
+    ///The volumes go from 0 to 1
+    pub fn set_volumes(&mut self, music: f32, effects: f32) {
+        self.music_volume = music.clamp(0.0, 1.0);
+        self.effects_volume = effects.clamp(0.0, 1.0);
+
+        if let Some(output) = &self.output {
+            if output.fading.is_none() {
+                if let Some(track) = &output.current {
+                    track.sink.set_volume(self.music_volume);
+                }
+            }
+        }
+    }
+
+    ///Fades the music and starts the next track when the current one is ending
+    pub fn update(&mut self, delta: &Duration) {
+        let Some(output) = &mut self.output else {
+            return;
+        };
+
+        if let Some(fading) = &output.fading {
+            output.fade += *delta;
+            let (out, inc) = crossfade_volumes(output.fade, CROSSFADE);
+            fading.set_volume(self.music_volume * out);
+            if let Some(track) = &output.current {
+                track.sink.set_volume(self.music_volume * inc);
+            }
+            if output.fade >= CROSSFADE {
+                output.fading = None;
+            }
+        }
+
+        let ending = match &mut output.current {
+            Some(track) => {
+                track.left = track.left.map(|t| t.saturating_sub(*delta));
+                track.sink.empty() || track.left.is_some_and(|t| t <= CROSSFADE)
+            }
+            None => false,
+        };
+        if ending && output.fading.is_none() {
+            self.next_track();
+        }
+    }
+
+    ///Crossfades to the next track of the playlist
+    pub fn next_track(&mut self) {
+        let Some(output) = &mut self.output else {
+            return;
+        };
+
+        let Some(path) = self.playlist.next_track().map(str::to_string) else {
+            return;
+        };
+
+        let track = std::fs::read(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| Decoder::new(Cursor::new(bytes)).map_err(|e| e.to_string()))
+            .and_then(|source| {
+                let sink = Sink::try_new(&output.handle).map_err(|e| e.to_string())?;
+                let left = source.total_duration();
+                sink.set_volume(0.0);
+                sink.append(source);
+                Ok(Track { sink, left })
+            });
+
+        match track {
+            Ok(track) => {
+                let old = output.current.replace(track);
+                match old {
+                    Some(old) if !old.sink.empty() => {
+                        output.fading = Some(old.sink);
+                        output.fade = Duration::ZERO;
+                    }
+                    _ => {
+                        output.fading = None;
+                        if let Some(track) = &output.current {
+                            track.sink.set_volume(self.music_volume);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                //The music stops instead of trying again every frame
+                warn!("Couldn't play the music in {path}: {e}");
+                output.current = None;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use rodio::{Decoder, OutputStream, Sink};
-    use std::fs::File;
-    use std::io::BufReader;
+    use super::*;
+
+    #[test]
+    fn playlist_order() {
+        let tracks = vec!["a".to_string(), "b".to_string()];
+
+        let mut once = Playlist::new(tracks.clone(), false);
+        assert_eq!(once.next_track(), Some("a"));
+        assert_eq!(once.next_track(), Some("b"));
+        assert_eq!(once.next_track(), None);
+
+        let mut looping = Playlist::new(tracks, true);
+        let played = (0..5).filter_map(|_| looping.next_track().map(str::to_string)).collect::<Vec<_>>();
+        assert_eq!(played, ["a", "b", "a", "b", "a"]);
+
+        assert_eq!(Playlist::new(vec![], true).next_track(), None);
+    }
+
+    #[test]
+    fn crossfade() {
+        let length = Duration::from_secs(2);
+        assert_eq!(crossfade_volumes(Duration::ZERO, length), (1.0, 0.0));
+        assert_eq!(crossfade_volumes(Duration::from_secs(1), length), (0.5, 0.5));
+        assert_eq!(crossfade_volumes(Duration::from_secs(3), length), (0.0, 1.0));
+    }
 
     #[test]
-    fn play_sound() {
-        let (_stream, stream_handle) = OutputStream::try_default().unwrap();
-        let sink = Sink::try_new(&stream_handle).unwrap();
+    fn null_sink() {
+        let mut audio = Audio::null(Playlist::default(), 2.0, -1.0);
+        assert!(audio.is_null());
 
-        // Load a sound from a file, using a path relative to Cargo.toml
-        let file = BufReader::new(File::open("assets/b.mp3").unwrap());
-        // Decode that sound file into a source
-        let source = Decoder::new(file).unwrap();
-        sink.append(source);
+        audio.next_track();
+        audio.update(&Duration::from_secs(1));
+        audio.play(Sound::Deal);
+        audio.play(Sound::Win);
 
-        sink.set_speed(1.4);
+        assert_eq!(audio.played(), [Sound::Deal, Sound::Win]);
 
-        sink.sleep_until_end();
+        for _ in 0..MAX_PLAYED {
+            audio.play(Sound::Chips);
+        }
+        assert_eq!(audio.played(), [Sound::Chips; MAX_PLAYED]);
     }
 }
@@ -0,0 +1,5 @@
+///Play the engine over TCP, with a server hosting the tables and clients sitting on them
+pub mod client;
+pub mod protocol;
+pub mod remote;
+pub mod server;
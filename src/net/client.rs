@@ -0,0 +1,171 @@
+use std::io::{self, BufReader};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use log::warn;
+
+use crate::core::action::GameMessage;
+use crate::game::player_state::PlayerAction;
+
+use super::protocol::{self, ClientMessage, ServerMessage};
+
+///Time waited before trying to connect again
+pub const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+///The connection of a player with a table server
+#[derive(Debug)]
+pub struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+
+    pub seat: usize,
+    pub names: Vec<String>,
+    pub stacks: Vec<i32>,
+}
+
+impl Client {
+    ///Joins the table with the name and waits to be seated
+    pub fn connect<A: ToSocketAddrs>(addr: A, name: &str) -> io::Result<Self> {
+        let mut writer = TcpStream::connect(addr)?;
+        writer.set_nodelay(true)?;
+        let mut reader = BufReader::new(writer.try_clone()?);
+
+        let name = name.to_string();
+        protocol::send(&mut writer, &ClientMessage::Join { name })?;
+
+        match protocol::receive(&mut reader)? {
+            ServerMessage::Welcome {
+                seat,
+                names,
+                stacks,
+            } => Ok(Client {
+                reader,
+                writer,
+                seat,
+                names,
+                stacks,
+            }),
+            _ => Err(io::ErrorKind::InvalidData.into()),
+        }
+    }
+
+    ///Blocks until the server sends a message
+    pub fn receive(&mut self) -> io::Result<ServerMessage> {
+        protocol::receive(&mut self.reader)
+    }
+
+    ///Answers the decision with the id
+    pub fn act(&mut self, id: u64, action: PlayerAction) -> io::Result<()> {
+        protocol::send(&mut self.writer, &ClientMessage::Act { id, action })
+    }
+
+    ///A handle to the socket, to change its timeouts or shut it down
+    pub fn stream(&self) -> &TcpStream {
+        &self.writer
+    }
+}
+
+///What happens with the connection of a session
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEvent {
+    Joined {
+        seat: usize,
+        names: Vec<String>,
+        stacks: Vec<i32>,
+    },
+    HandEnded {
+        stacks: Vec<i32>,
+    },
+    ///The connection was lost, the session is trying to join again
+    Lost,
+    Closed,
+}
+
+///Keeps a client sitting at the table in other threads, joining again when the connection is lost
+///<br>The events of the hands are sent through `game_tx` like a local engine does, and the actions
+///received from `player_rx` are sent to the server
+pub fn spawn_session(
+    addr: String,
+    name: String,
+    game_tx: mpsc::Sender<GameMessage>,
+    player_rx: mpsc::Receiver<PlayerAction>,
+) -> mpsc::Receiver<SessionEvent> {
+    let (session_tx, session_rx) = mpsc::channel();
+    let writer: Arc<Mutex<Option<TcpStream>>> = Arc::default();
+    //The last decision asked by the server, the actions answer it
+    let decision = Arc::new(AtomicU64::new(0));
+
+    //Actions taken while the connection is lost are dropped, the server folds them anyway
+    let actions = writer.clone();
+    let answered = decision.clone();
+    thread::spawn(move || {
+        for action in player_rx {
+            let mut writer = actions.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(w) = writer.as_mut() {
+                let id = answered.load(Ordering::Relaxed);
+                protocol::send(w, &ClientMessage::Act { id, action }).ok();
+            }
+        }
+    });
+
+    thread::spawn(move || loop {
+        match Client::connect(&addr, &name) {
+            Ok(mut client) => {
+                *writer.lock().unwrap_or_else(|e| e.into_inner()) =
+                    client.stream().try_clone().ok();
+
+                let joined = SessionEvent::Joined {
+                    seat: client.seat,
+                    names: client.names.clone(),
+                    stacks: client.stacks.clone(),
+                };
+                if session_tx.send(joined).is_err() {
+                    return;
+                }
+
+                loop {
+                    let sent = match client.receive() {
+                        Ok(ServerMessage::Event(msg)) => game_tx.send(msg).is_ok(),
+                        Ok(ServerMessage::Decide { id, .. }) => {
+                            decision.store(id, Ordering::Relaxed);
+                            true
+                        }
+                        Ok(ServerMessage::HandEnded { stacks }) => {
+                            session_tx.send(SessionEvent::HandEnded { stacks }).is_ok()
+                        }
+                        Ok(ServerMessage::Closed) => {
+                            session_tx.send(SessionEvent::Closed).ok();
+                            return;
+                        }
+                        Ok(_) => true,
+                        Err(e) => {
+                            warn!("Lost connection with the table: {e}");
+                            break;
+                        }
+                    };
+                    if !sent {
+                        return; //The game was closed
+                    }
+                }
+
+                *writer.lock().unwrap_or_else(|e| e.into_inner()) = None;
+                if session_tx.send(SessionEvent::Lost).is_err() {
+                    return;
+                }
+            }
+            Err(e) => {
+                warn!("Couldn't join the table in {addr}: {e}");
+                if session_tx.send(SessionEvent::Lost).is_err() {
+                    return;
+                }
+            }
+        }
+
+        thread::sleep(RECONNECT_DELAY);
+    });
+
+    session_rx
+}
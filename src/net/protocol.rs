@@ -0,0 +1,79 @@
+//! Line-delimited JSON protocol between the table server and its clients
+//!
+//! Every message is a JSON object in a single line ended by `\n`. The client starts sending a
+//! [`ClientMessage::Join`] and the server answers with a [`ServerMessage::Welcome`], after that
+//! the server streams the events of every hand and asks for decisions:
+//!
+//! ```text
+//! -> {"Join":{"name":"Alice"}}
+//! <- {"Welcome":{"seat":1,"names":["Bot1","Alice","Bob"],"stacks":[1000,1000,1000]}}
+//! <- {"Event":{"action":{"DealStartHand":{"hand":[...],"i":1}},"state":{...},"view":{"Private":1}}}
+//! <- {"Decide":{"id":1,"state":{...},"can_raise":false,"timeout_ms":30000}}
+//! -> {"Act":{"id":1,"action":"Call"}}
+//! <- {"HandEnded":{"stacks":[999,1002,999]}}
+//! ```
+//!
+//! A client only receives the cards dealt to its own seat, the cards of the rest of the players
+//! are only sent when they are shown at the showdown. If a decision isn't answered before the
+//! timeout the player folds.
+//! The actions carry the id of the decision they answer, the late answers are discarded.
+
+use std::io::{self, BufRead, Write};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::core::{action::GameMessage, state::GameState};
+use crate::game::player_state::PlayerAction;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ServerMessage {
+    ///The seat given to the client and the players of the table
+    Welcome {
+        seat: usize,
+        names: Vec<String>,
+        stacks: Vec<i32>,
+    },
+    ///An event of the hand the client is allowed to see
+    Event(GameMessage),
+    ///The server waits for the action of the client
+    Decide {
+        id: u64,
+        state: GameState,
+        can_raise: bool,
+        timeout_ms: u64,
+    },
+    ///The money of every seat after the hand
+    HandEnded { stacks: Vec<i32> },
+    ///The table is closed, no more hands will be played
+    Closed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ClientMessage {
+    Join {
+        name: String,
+    },
+    ///Answers the decision with the id, raises are the amount over the call
+    Act {
+        id: u64,
+        action: PlayerAction,
+    },
+}
+
+///Writes the message as a single line of JSON
+pub fn send<T: Serialize, W: Write>(w: &mut W, msg: &T) -> io::Result<()> {
+    let mut line = serde_json::to_string(msg)?;
+    line.push('\n');
+    w.write_all(line.as_bytes())?;
+    w.flush()
+}
+
+///Reads a line and parses it, an error is returned if the connection is closed
+pub fn receive<T: DeserializeOwned, R: BufRead>(r: &mut R) -> io::Result<T> {
+    let mut line = String::new();
+    if r.read_line(&mut line)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(serde_json::from_str(&line)?)
+}
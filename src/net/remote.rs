@@ -0,0 +1,260 @@
+use std::io::{self, BufReader};
+use std::net::TcpStream;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use log::warn;
+
+use crate::core::{
+    action::{game_action::GameAction, GameMessage},
+    error::EngineError,
+    player::{Player, PlayerAction, PlayerHand},
+    state::GameState,
+};
+use crate::game::player_state;
+
+use super::protocol::{self, ClientMessage, ServerMessage};
+
+///The socket of a client, kept between hands
+#[derive(Debug)]
+pub struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    pub name: String,
+    pub connected: bool,
+    ///The cards dealt in the current hand, sent again if the client reconnects
+    dealt: Option<GameMessage>,
+    ///The id of the last decision asked
+    decision: u64,
+}
+
+impl Connection {
+    ///Waits for the client to join the table
+    pub fn accept(stream: TcpStream, timeout: Duration) -> io::Result<Self> {
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+        stream.set_read_timeout(Some(timeout))?;
+
+        let mut reader = BufReader::new(stream.try_clone()?);
+        match protocol::receive(&mut reader)? {
+            ClientMessage::Join { name } => Ok(Connection {
+                reader,
+                writer: stream,
+                name,
+                connected: true,
+                dealt: None,
+                decision: 0,
+            }),
+            _ => Err(io::ErrorKind::InvalidData.into()),
+        }
+    }
+
+    pub fn send(&mut self, msg: &ServerMessage) {
+        match msg {
+            ServerMessage::Event(event) => {
+                if let GameAction::DealStartHand { .. } = event.action {
+                    self.dealt = Some(event.clone());
+                }
+            }
+            ServerMessage::HandEnded { .. } => self.dealt = None,
+            _ => {}
+        }
+
+        if self.connected && protocol::send(&mut self.writer, msg).is_err() {
+            warn!("Lost connection with {}", self.name);
+            self.connected = false;
+        }
+    }
+
+    ///Takes the socket of a client that joined again with the same name
+    pub fn rejoin(&mut self, other: Connection, welcome: &ServerMessage) {
+        self.reader = other.reader;
+        self.writer = other.writer;
+        self.connected = true;
+
+        self.send(welcome);
+        if let Some(dealt) = self.dealt.clone() {
+            self.send(&ServerMessage::Event(dealt));
+        }
+    }
+
+    ///Asks the client to decide and waits for the answer
+    pub fn decide(
+        &mut self,
+        state: &GameState,
+        can_raise: bool,
+        timeout: Duration,
+    ) -> Result<player_state::PlayerAction, EngineError> {
+        self.decision += 1;
+        self.send(&ServerMessage::Decide {
+            id: self.decision,
+            state: state.clone(),
+            can_raise,
+            timeout_ms: timeout.as_millis() as u64,
+        });
+        self.receive_action(self.decision, timeout)
+    }
+
+    ///Waits for the action that answers the decision, the rest of the messages are ignored
+    ///<br>An answer that arrives after its decision timed out is discarded
+    fn receive_action(
+        &mut self,
+        id: u64,
+        timeout: Duration,
+    ) -> Result<player_state::PlayerAction, EngineError> {
+        if !self.connected {
+            return Err(EngineError::ConnectionError);
+        }
+
+        let start = Instant::now();
+        loop {
+            let left = timeout
+                .checked_sub(start.elapsed())
+                .filter(|d| !d.is_zero())
+                .ok_or(EngineError::TimeoutError)?;
+            self.reader
+                .get_ref()
+                .set_read_timeout(Some(left))
+                .map_err(|_| EngineError::ConnectionError)?;
+
+            match protocol::receive(&mut self.reader) {
+                Ok(ClientMessage::Act {
+                    id: answered,
+                    action,
+                }) if answered == id => return Ok(action),
+                Ok(_) => continue,
+                Err(e)
+                    if e.kind() == io::ErrorKind::WouldBlock
+                        || e.kind() == io::ErrorKind::TimedOut =>
+                {
+                    return Err(EngineError::TimeoutError)
+                }
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => continue,
+                Err(_) => {
+                    self.connected = false;
+                    return Err(EngineError::ConnectionError);
+                }
+            }
+        }
+    }
+}
+
+///A player in another machine, the decisions are asked through the connection
+#[derive(Debug)]
+pub struct RemotePlayer {
+    hand: Option<PlayerHand>,
+    conn: Arc<Mutex<Connection>>,
+    timeout: Duration,
+    deadline: Option<Instant>,
+}
+
+impl Player for RemotePlayer {
+    fn cards(&self) -> Option<PlayerHand> {
+        self.hand
+    }
+
+    fn give_cards(&mut self, hand: PlayerHand) {
+        self.hand = Some(hand);
+    }
+
+    fn blind(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+        let chosen = self.ask(state, false)?;
+        PlayerAction::from_chosen(chosen, state, i, false)
+    }
+
+    fn play(&mut self, state: &GameState, i: usize) -> Result<PlayerAction, EngineError> {
+        let chosen = self.ask(state, true)?;
+        PlayerAction::from_chosen(chosen, state, i, true)
+    }
+
+    fn set_deadline(&mut self, deadline: Option<Instant>) {
+        self.deadline = deadline;
+    }
+
+    fn can_block(&self) -> bool {
+        true
+    }
+
+    fn observe(&mut self, action: &GameAction, state: &GameState) {
+        let msg = GameMessage::new(action.clone(), state.clone());
+        self.connection().send(&ServerMessage::Event(msg));
+    }
+}
+
+impl RemotePlayer {
+    pub fn new(conn: Arc<Mutex<Connection>>, timeout: Duration) -> Self {
+        RemotePlayer {
+            hand: None,
+            conn,
+            timeout,
+            deadline: None,
+        }
+    }
+
+    fn connection(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn ask(
+        &mut self,
+        state: &GameState,
+        can_raise: bool,
+    ) -> Result<player_state::PlayerAction, EngineError> {
+        //The engine clock includes the time bank of the player
+        let timeout = match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => self.timeout,
+        };
+
+        self.connection().decide(state, can_raise, timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::thread;
+
+    use player_state::PlayerAction::{Call, Fold};
+
+    use super::*;
+
+    #[test]
+    fn late_answers_are_discarded() -> io::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        let client = thread::spawn(move || -> io::Result<()> {
+            let mut stream = TcpStream::connect(addr)?;
+            let name = "Alice".to_string();
+            protocol::send(&mut stream, &ClientMessage::Join { name })?;
+            //The first decision timed out before its answer arrived
+            protocol::send(
+                &mut stream,
+                &ClientMessage::Act {
+                    id: 1,
+                    action: Fold,
+                },
+            )?;
+            protocol::send(
+                &mut stream,
+                &ClientMessage::Act {
+                    id: 2,
+                    action: Call,
+                },
+            )?;
+
+            //Wait until the server closes the socket
+            let mut reader = BufReader::new(stream);
+            while protocol::receive::<ServerMessage, _>(&mut reader).is_ok() {}
+            Ok(())
+        });
+
+        let (stream, _) = listener.accept()?;
+        let mut conn = Connection::accept(stream, Duration::from_secs(5))?;
+        assert_eq!(conn.receive_action(2, Duration::from_secs(5)), Ok(Call));
+        drop(conn);
+
+        client.join().unwrap()
+    }
+}
@@ -0,0 +1,443 @@
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use log::{info, warn};
+
+use crate::core::{
+    action::test_queue::EmptyQueue,
+    clock::TimeLimit,
+    engine::Engine,
+    player::{montecarlo::MontecarloPlayer, Player},
+};
+
+use super::{
+    protocol::ServerMessage,
+    remote::{Connection, RemotePlayer},
+};
+
+///Time waited between checks of the listener for new clients
+const ACCEPT_POLL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone)]
+pub struct TableConfig {
+    pub seats: usize,
+    ///The first seats are given to the clients, the rest are played by bots
+    pub remote_seats: usize,
+    pub stack: i32,
+    pub blind: i32,
+    ///Time a client has to decide before checking or folding
+    pub timeout: Duration,
+    ///Extra time every player can spend in a hand when a decision takes longer
+    pub time_bank: Duration,
+    ///The table closes after this number of hands, or when only one player has money
+    pub hands: Option<usize>,
+    pub bot: fn() -> Box<dyn Player>,
+}
+
+impl Default for TableConfig {
+    fn default() -> Self {
+        TableConfig {
+            seats: 6,
+            remote_seats: 1,
+            stack: 1000,
+            blind: 1,
+            timeout: Duration::from_secs(30),
+            time_bank: Duration::from_secs(60),
+            hands: None,
+            bot: || Box::<MontecarloPlayer>::default(),
+        }
+    }
+}
+
+///Hosts a single table, the clients are seated in the order they join
+#[derive(Debug)]
+pub struct TableServer {
+    listener: TcpListener,
+    config: TableConfig,
+}
+
+impl TableServer {
+    pub fn bind<A: ToSocketAddrs>(addr: A, config: TableConfig) -> io::Result<Self> {
+        if config.seats < 2 || config.remote_seats > config.seats {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Bad table configuration",
+            ));
+        }
+
+        Ok(TableServer {
+            listener: TcpListener::bind(addr)?,
+            config,
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    ///Waits for every client and plays hands until the table closes, returning the final stacks
+    pub fn run(self) -> io::Result<Vec<i32>> {
+        let connections = self.accept_clients()?;
+        let config = self.config;
+
+        let names: Vec<_> = (0..config.seats)
+            .map(|i| match connections.get(i) {
+                Some(conn) => lock(conn).name.clone(),
+                None => format!("Bot{}", i + 1),
+            })
+            .collect();
+        let mut stacks = vec![config.stack; config.seats];
+        let table = Arc::new(Mutex::new(stacks.clone()));
+
+        for (seat, conn) in connections.iter().enumerate() {
+            lock(conn).send(&ServerMessage::Welcome {
+                seat,
+                names: names.clone(),
+                stacks: stacks.clone(),
+            });
+        }
+
+        //The clients that lose the connection can join again while the table is open
+        let closed = Arc::new(AtomicBool::new(false));
+        let rejoin = {
+            let listener = self.listener.try_clone()?;
+            listener.set_nonblocking(true)?;
+            let connections = connections.clone();
+            let table = table.clone();
+            let closed = closed.clone();
+            let timeout = config.timeout;
+
+            thread::spawn(move || {
+                rejoin_clients(listener, &connections, &names, &table, &closed, timeout)
+            })
+        };
+
+        let mut played = 0;
+        while stacks.iter().filter(|&&s| s > 0).count() > 1
+            && config.hands.map(|h| played < h).unwrap_or(true)
+        {
+            let players = (0..config.seats)
+                .map(|i| match connections.get(i) {
+                    Some(conn) => Box::new(RemotePlayer::new(conn.clone(), config.timeout)) as _,
+                    None => (config.bot)(),
+                })
+                .collect();
+
+            let limit = TimeLimit {
+                decision: config.timeout,
+                bank: config.time_bank,
+            };
+            let engine = Engine::new(players, Box::<EmptyQueue>::default())
+                .map_err(io::Error::other)?
+                .with_time_limit(limit);
+            stacks = engine.run(stacks, config.blind).map_err(io::Error::other)?;
+            played += 1;
+            info!("Hand {played} ended with stacks {stacks:?}");
+            *table.lock().unwrap_or_else(|e| e.into_inner()) = stacks.clone();
+
+            for conn in &connections {
+                lock(conn).send(&ServerMessage::HandEnded {
+                    stacks: stacks.clone(),
+                });
+            }
+        }
+
+        closed.store(true, Ordering::Relaxed);
+        rejoin.join().ok();
+        for conn in &connections {
+            lock(conn).send(&ServerMessage::Closed);
+        }
+
+        Ok(stacks)
+    }
+
+    fn accept_clients(&self) -> io::Result<Vec<Arc<Mutex<Connection>>>> {
+        let config = &self.config;
+        let (joined_tx, joined_rx) = mpsc::channel();
+        let mut connections = Vec::new();
+
+        self.listener.set_nonblocking(true)?;
+        while connections.len() < config.remote_seats {
+            match self.listener.accept() {
+                Ok((stream, addr)) => handshake(stream, addr, config.timeout, &joined_tx),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => thread::sleep(ACCEPT_POLL),
+                Err(e) => return Err(e),
+            }
+
+            for (conn, addr) in joined_rx.try_iter() {
+                if connections.len() < config.remote_seats {
+                    info!("{} joined the table from {addr}", conn.name);
+                    connections.push(Arc::new(Mutex::new(conn)));
+                } else {
+                    warn!("{} can't join, the table is full", conn.name);
+                }
+            }
+        }
+
+        Ok(connections)
+    }
+}
+
+///Waits for the client to join in another thread, so a slow client doesn't keep the rest waiting
+fn handshake(
+    stream: TcpStream,
+    addr: SocketAddr,
+    timeout: Duration,
+    joined_tx: &mpsc::Sender<(Connection, SocketAddr)>,
+) {
+    let joined_tx = joined_tx.clone();
+    thread::spawn(move || match Connection::accept(stream, timeout) {
+        Ok(conn) => {
+            joined_tx.send((conn, addr)).ok();
+        }
+        Err(e) => warn!("Rejected connection from {addr}: {e}"),
+    });
+}
+
+///Gives the seat back to the disconnected clients that join with the same name
+fn rejoin_clients(
+    listener: TcpListener,
+    connections: &[Arc<Mutex<Connection>>],
+    names: &[String],
+    stacks: &Mutex<Vec<i32>>,
+    closed: &AtomicBool,
+    timeout: Duration,
+) {
+    let (joined_tx, joined_rx) = mpsc::channel();
+
+    while !closed.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, addr)) => handshake(stream, addr, timeout, &joined_tx),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => thread::sleep(ACCEPT_POLL),
+            Err(e) => {
+                warn!("Couldn't accept connections: {e}");
+                return;
+            }
+        }
+
+        for (new, addr) in joined_rx.try_iter() {
+            let seat = connections.iter().position(|c| {
+                let c = lock(c);
+                !c.connected && c.name == new.name
+            });
+            match seat {
+                Some(seat) => {
+                    info!("{} joined the table again from {addr}", new.name);
+                    let welcome = ServerMessage::Welcome {
+                        seat,
+                        names: names.to_vec(),
+                        stacks: stacks.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+                    };
+                    lock(&connections[seat]).rejoin(new, &welcome);
+                }
+                None => warn!(
+                    "{} can't join, there's no free seat with that name",
+                    new.name
+                ),
+            }
+        }
+    }
+}
+
+fn lock(conn: &Mutex<Connection>) -> std::sync::MutexGuard<'_, Connection> {
+    conn.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Instant;
+
+    use crate::core::{
+        action::game_action::GameAction,
+        error::EngineError,
+        player::{dummy::DummyPlayer, PlayerAction as CorePlayerAction, PlayerHand},
+        state::GameState,
+    };
+    use crate::game::player_state::PlayerAction;
+    use crate::net::client::Client;
+
+    use super::*;
+
+    fn table(remote_seats: usize, timeout: Duration) -> io::Result<TableServer> {
+        let config = TableConfig {
+            seats: 4,
+            remote_seats,
+            stack: 100,
+            blind: 1,
+            timeout,
+            time_bank: Duration::ZERO,
+            hands: Some(2),
+            bot: || Box::<DummyPlayer>::default(),
+        };
+
+        TableServer::bind("127.0.0.1:0", config)
+    }
+
+    ///Calls every decision and returns the events and the hands that ended
+    fn calling_client(client: &mut Client) -> io::Result<(Vec<GameAction>, usize)> {
+        let mut events = Vec::new();
+        let mut hands = 0;
+
+        loop {
+            match client.receive()? {
+                ServerMessage::Event(msg) => events.push(msg.action),
+                ServerMessage::Decide { id, .. } => client.act(id, PlayerAction::Call)?,
+                ServerMessage::HandEnded { .. } => hands += 1,
+                ServerMessage::Closed => return Ok((events, hands)),
+                ServerMessage::Welcome { .. } => {}
+            }
+        }
+    }
+
+    #[test]
+    fn loopback_clients() -> io::Result<()> {
+        let server = table(2, Duration::from_secs(10))?;
+        let addr = server.local_addr()?;
+        let server = thread::spawn(move || server.run());
+
+        let clients: Vec<_> = ["Alice", "Bob"]
+            .iter()
+            .map(|name| {
+                thread::spawn(move || -> io::Result<_> {
+                    let mut client = Client::connect(addr, name)?;
+                    let result = calling_client(&mut client)?;
+                    Ok((client.seat, client.names, result))
+                })
+            })
+            .collect();
+
+        let stacks = server.join().unwrap()?;
+        assert_eq!(stacks.iter().sum::<i32>(), 400);
+
+        for client in clients {
+            let (seat, names, (events, hands)) = client.join().unwrap()?;
+            assert_eq!(names.len(), 4);
+            //The table may close before if a player takes all the money
+            assert!((1..=2).contains(&hands));
+
+            //Only the own cards are dealt to every client
+            let deals: Vec<_> = events
+                .iter()
+                .filter_map(|a| match a {
+                    GameAction::DealStartHand { i, .. } => Some(*i),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(deals, vec![seat; hands]);
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, Default)]
+    struct SlowPlayer(DummyPlayer);
+
+    impl Player for SlowPlayer {
+        fn cards(&self) -> Option<PlayerHand> {
+            self.0.cards()
+        }
+
+        fn give_cards(&mut self, hand: PlayerHand) {
+            self.0.give_cards(hand)
+        }
+
+        fn blind(&mut self, state: &GameState, i: usize) -> Result<CorePlayerAction, EngineError> {
+            thread::sleep(Duration::from_millis(20));
+            self.0.blind(state, i)
+        }
+
+        fn play(&mut self, state: &GameState, i: usize) -> Result<CorePlayerAction, EngineError> {
+            thread::sleep(Duration::from_millis(20));
+            self.0.play(state, i)
+        }
+    }
+
+    #[test]
+    fn client_rejoins() -> io::Result<()> {
+        let config = TableConfig {
+            seats: 3,
+            remote_seats: 1,
+            stack: 100,
+            blind: 1,
+            timeout: Duration::from_secs(10),
+            time_bank: Duration::ZERO,
+            hands: Some(10),
+            bot: || Box::<SlowPlayer>::default(),
+        };
+        let server = TableServer::bind("127.0.0.1:0", config)?;
+        let addr = server.local_addr()?;
+        let server = thread::spawn(move || server.run());
+
+        let client = Client::connect(addr, "Alice")?;
+        let seat = client.seat;
+        drop(client);
+
+        //The seat is only free when the server notices the lost connection
+        let mut client = loop {
+            match Client::connect(addr, "Alice") {
+                Ok(client) => break client,
+                Err(_) => thread::sleep(Duration::from_millis(10)),
+            }
+        };
+        assert_eq!(client.seat, seat);
+
+        let (_, hands) = calling_client(&mut client)?;
+        assert!(hands > 0);
+        server.join().unwrap()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn slow_handshake_doesnt_block_the_lobby() -> io::Result<()> {
+        let timeout = Duration::from_secs(30);
+        let server = table(1, timeout)?;
+        let addr = server.local_addr()?;
+        let start = Instant::now();
+        let server = thread::spawn(move || server.run());
+
+        //Connects but never joins
+        let _idle = TcpStream::connect(addr)?;
+        thread::sleep(Duration::from_millis(100));
+
+        let mut client = Client::connect(addr, "Alice")?;
+        let (_, hands) = calling_client(&mut client)?;
+        assert!(hands > 0);
+        server.join().unwrap()?;
+        assert!(start.elapsed() < timeout);
+
+        Ok(())
+    }
+
+    #[test]
+    fn silent_client_folds() -> io::Result<()> {
+        let server = table(1, Duration::from_millis(100))?;
+        let addr = server.local_addr()?;
+        let server = thread::spawn(move || server.run());
+
+        let mut client = Client::connect(addr, "Silent")?;
+        let mut timeouts = 0;
+        loop {
+            match client.receive()? {
+                ServerMessage::Event(msg) => {
+                    if let GameAction::TimedOut { i, .. } = msg.action {
+                        assert_eq!(i, client.seat);
+                        timeouts += 1;
+                    }
+                }
+                ServerMessage::Closed => break,
+                _ => {}
+            }
+        }
+
+        server.join().unwrap()?;
+        assert_eq!(timeouts, 2);
+
+        Ok(())
+    }
+}
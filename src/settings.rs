@@ -0,0 +1,313 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter};
+use std::path::Path;
+use std::time::Duration;
+
+use log::warn;
+use sdl2::keyboard::Keycode;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::core::player::montecarlo::SIM_ROUNDS;
+use crate::game::setup::{Difficulty, Seat};
+use crate::graphic::Display;
+use crate::theme::CLASSIC;
+
+///File where the preferences are kept
+pub const SETTINGS_PATH: &str = "settings.json";
+
+///The animations go from half to double of their normal speed
+pub const MIN_ANIMATION_SPEED: f32 = 0.5;
+pub const MAX_ANIMATION_SPEED: f32 = 2.0;
+
+///Preferences of the player, loaded at startup and saved from the settings screen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    ///The delays between the plays are divided by this
+    pub animation_speed: f32,
+    pub display: Display,
+    ///The volumes go from 0 to 1
+    #[serde(alias = "volume")]
+    pub music_volume: f32,
+    pub effects_volume: f32,
+    ///The tracks of the background music, played in a loop
+    #[serde(deserialize_with = "tracks")]
+    pub music: Vec<String>,
+    ///The name of the theme of the cards and the table
+    pub theme: String,
+    ///Clubs and diamonds with their own colours
+    pub four_color_deck: bool,
+    ///The back design of the cards of the theme
+    pub card_back: usize,
+
+    pub difficulty: Difficulty,
+    ///Simulations of the hard bots before every decision
+    pub sim_rounds: i32,
+    ///Seconds to decide, the decisions aren't timed without it
+    pub time_limit: Option<u64>,
+    ///Shows the odds of the hand and a suggested action while playing
+    pub assistant: bool,
+    ///The names and the pictures of the opponents, from the seat after the local player
+    pub seats: Vec<Seat>,
+
+    pub keys: KeyBindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            animation_speed: 1.0,
+            display: Display::default(),
+            music_volume: 0.5,
+            effects_volume: 0.8,
+            music: vec!["assets/music/main.wav".to_string()],
+            theme: CLASSIC.to_string(),
+            four_color_deck: false,
+            card_back: 0,
+            difficulty: Difficulty::default(),
+            sim_rounds: SIM_ROUNDS,
+            time_limit: Some(30),
+            assistant: false,
+            seats: vec![],
+            keys: KeyBindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    ///The default settings are used if there's no file or it can't be read
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Self {
+        match Settings::load(&path) {
+            Ok(settings) => settings,
+            Err(e) => {
+                if e.kind() != io::ErrorKind::NotFound {
+                    warn!("Couldn't read the settings, the default ones are used: {e}");
+                }
+                Settings::default()
+            }
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    ///The length of an animation at the chosen speed
+    pub fn animation(&self, delay: Duration) -> Duration {
+        delay.div_f32(
+            self.animation_speed
+                .clamp(MIN_ANIMATION_SPEED, MAX_ANIMATION_SPEED),
+        )
+    }
+}
+
+///The older settings have a single track instead of a list
+fn tracks<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tracks {
+        One(String),
+        List(Vec<String>),
+    }
+
+    Ok(match Tracks::deserialize(deserializer)? {
+        Tracks::One(track) => vec![track],
+        Tracks::List(tracks) => tracks,
+    })
+}
+
+///A key saved with its SDL name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Key(pub Keycode);
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Keycode::from_name(&name)
+            .map(Key)
+            .ok_or_else(|| serde::de::Error::custom(format!("Unknown key {name}")))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub pause: Key,
+    pub debug: Key,
+    pub save: Key,
+    pub load: Key,
+    pub profile: Key,
+    ///Leaves the game from the pause menu
+    pub menu: Key,
+    pub next_hand: Key,
+    pub fold: Key,
+    ///Checks when there's nothing to call
+    pub call: Key,
+    pub raise: Key,
+    pub assistant: Key,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        KeyBindings {
+            pause: Key(Keycode::P),
+            debug: Key(Keycode::D),
+            save: Key(Keycode::S),
+            load: Key(Keycode::L),
+            profile: Key(Keycode::I),
+            menu: Key(Keycode::M),
+            next_hand: Key(Keycode::Return),
+            fold: Key(Keycode::F),
+            call: Key(Keycode::C),
+            raise: Key(Keycode::R),
+            assistant: Key(Keycode::H),
+        }
+    }
+}
+
+impl KeyBindings {
+    pub const NAMES: [&'static str; 11] = [
+        "Pause",
+        "Debug",
+        "Save",
+        "Load",
+        "Profile",
+        "Menu",
+        "Next hand",
+        "Fold",
+        "Check/Call",
+        "Raise",
+        "Assistant",
+    ];
+
+    ///The keys in the order of [`KeyBindings::NAMES`]
+    pub fn keys(&self) -> [Key; 11] {
+        [
+            self.pause,
+            self.debug,
+            self.save,
+            self.load,
+            self.profile,
+            self.menu,
+            self.next_hand,
+            self.fold,
+            self.call,
+            self.raise,
+            self.assistant,
+        ]
+    }
+
+    pub fn keys_mut(&mut self) -> [&mut Key; 11] {
+        [
+            &mut self.pause,
+            &mut self.debug,
+            &mut self.save,
+            &mut self.load,
+            &mut self.profile,
+            &mut self.menu,
+            &mut self.next_hand,
+            &mut self.fold,
+            &mut self.call,
+            &mut self.raise,
+            &mut self.assistant,
+        ]
+    }
+    ///Changes the key of the binding, a binding that already used the key gets the old one
+    ///<br>Returns the binding that was swapped
+    pub fn rebind(&mut self, i: usize, key: Key) -> Option<usize> {
+        let old = self.keys()[i];
+        let swapped = self.keys().iter().position(|&k| k == key).filter(|&j| j != i);
+
+        let mut keys = self.keys_mut();
+        *keys[i] = key;
+        if let Some(j) = swapped {
+            *keys[j] = old;
+        }
+
+        swapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load() -> io::Result<()> {
+        let mut settings = Settings {
+            animation_speed: 2.0,
+            music_volume: 0.1,
+            ..Default::default()
+        };
+        settings.keys.pause = Key(Keycode::Space);
+
+        let path = std::env::temp_dir().join(format!("poker-settings-{}.json", std::process::id()));
+        settings.save(&path)?;
+        let loaded = Settings::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(loaded, settings);
+        assert_eq!(Settings::load_or_default(&path), Settings::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_fields_are_default() {
+        let settings: Settings = serde_json::from_str(r#"{"music_volume": 0.2, "keys": {"debug": "F1"}}"#).unwrap();
+
+        assert_eq!(settings.music_volume, 0.2);
+        assert_eq!(settings.keys.debug, Key(Keycode::F1));
+        assert_eq!(settings.keys.pause, Key(Keycode::P));
+        assert_eq!(settings.animation_speed, 1.0);
+    }
+
+    #[test]
+    fn rebinding_swaps_the_used_keys() {
+        let mut keys = KeyBindings::default();
+
+        //The fold key goes to the pause binding
+        assert_eq!(keys.rebind(0, Key(Keycode::F)), Some(7));
+        assert_eq!(keys.pause, Key(Keycode::F));
+        assert_eq!(keys.fold, Key(Keycode::P));
+
+        assert_eq!(keys.rebind(0, Key(Keycode::F)), None);
+        assert_eq!(keys.rebind(1, Key(Keycode::F2)), None);
+        assert_eq!(keys.debug, Key(Keycode::F2));
+
+        let all = keys.keys();
+        assert!(all.iter().all(|k| all.iter().filter(|&o| o == k).count() == 1));
+    }
+
+    #[test]
+    fn old_music_setting() {
+        let settings: Settings = serde_json::from_str(r#"{"volume": 0.3, "music": "assets/b.mp3"}"#).unwrap();
+        assert_eq!(settings.music, ["assets/b.mp3"]);
+        assert_eq!(settings.music_volume, 0.3);
+
+        let settings: Settings = serde_json::from_str(r#"{"music": ["a.mp3", "b.mp3"]}"#).unwrap();
+        assert_eq!(settings.music, ["a.mp3", "b.mp3"]);
+    }
+
+    #[test]
+    fn animation_speed() {
+        let settings = Settings {
+            animation_speed: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(settings.animation(Duration::from_millis(1000)), Duration::from_millis(500));
+    }
+}
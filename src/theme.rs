@@ -0,0 +1,287 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use sdl2::{pixels::Color, rect::Rect};
+use serde::{Deserialize, Serialize};
+
+use crate::core::card::{Card, Suit, Value, SUITS, VALUES};
+
+///Every folder here with a manifest is a theme
+pub const THEMES_DIR: &str = "assets/themes";
+pub const MANIFEST: &str = "theme.json";
+///The theme of the assets of the game, it doesn't need a manifest
+pub const CLASSIC: &str = "Classic";
+
+///A sprite sheet with a row for every suit and a column for every value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CardSheet {
+    pub path: String,
+    ///The size of every card in the image
+    pub card_width: u32,
+    pub card_height: u32,
+    ///The values of the columns, from the left
+    pub values: Vec<Value>,
+    ///The suits of the rows, from the top
+    pub suits: Vec<Suit>,
+    ///Column and row of every back design
+    pub backs: Vec<(i32, i32)>,
+    ///The same sheet with a colour for every suit. Without it the clubs and the diamonds of the
+    ///sheet are recoloured
+    pub four_color_path: Option<String>,
+}
+
+impl Default for CardSheet {
+    fn default() -> Self {
+        CardSheet {
+            path: "assets/cards.png".to_string(),
+            card_width: 200,
+            card_height: 291,
+            values: VALUES.to_vec(),
+            suits: SUITS.to_vec(),
+            backs: vec![(2, 4)],
+            four_color_path: None,
+        }
+    }
+}
+
+impl CardSheet {
+    ///Where the card is in the sheet, or the chosen back without a card
+    pub fn rect(&self, card: Option<Card>, back: usize) -> Rect {
+        let (column, row) = match card {
+            Some(card) => (
+                position(&self.values, &card.value),
+                position(&self.suits, &card.suit),
+            ),
+            None => self
+                .backs
+                .get(back)
+                .or(self.backs.first())
+                .copied()
+                .unwrap_or_default(),
+        };
+
+        Rect::new(
+            column * self.card_width as i32,
+            row * self.card_height as i32,
+            self.card_width,
+            self.card_height,
+        )
+    }
+
+    ///The size of a card drawn with the width, with the proportions of the sheet
+    pub fn size(&self, width: u32) -> (u32, u32) {
+        let ratio = self.card_height as f32 / self.card_width.max(1) as f32;
+        (width, (width as f32 * ratio) as u32)
+    }
+
+    ///Turns the clubs green and the diamonds blue in the pixels of the sheet, 4 bytes per pixel
+    ///in RGBA order
+    pub fn recolor_suits(&self, pixels: &mut [u8], pitch: usize) {
+        let columns = self.values.len() * self.card_width as usize;
+
+        for (row, &suit) in self.suits.iter().enumerate() {
+            let top = row * self.card_height as usize;
+            for y in top..top + self.card_height as usize {
+                for x in 0..columns {
+                    let k = y * pitch + 4 * x;
+                    if let Some(px) = pixels.get_mut(k..k + 4) {
+                        let recolored = four_color(suit, [px[0], px[1], px[2], px[3]]);
+                        px.copy_from_slice(&recolored);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn position<T: PartialEq>(items: &[T], item: &T) -> i32 {
+    items.iter().position(|i| i == item).unwrap_or_default() as i32
+}
+
+///The colour of a pixel of the suit in a four-colour deck, the black clubs are green and the red
+///diamonds are blue
+pub fn four_color(suit: Suit, [r, g, b, a]: [u8; 4]) -> [u8; 4] {
+    match suit {
+        Suit::Club if r.max(g).max(b) < 100 => [r, g.saturating_add(120), b, a],
+        Suit::Diamond if r > 120 && r / 2 > g.max(b) => [b, g, r, a],
+        _ => [r, g, b, a],
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Palette {
+    ///The colour of the white text
+    pub text: (u8, u8, u8),
+    ///The table is painted with it when there's no background
+    pub felt: (u8, u8, u8),
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            text: (255, 255, 255),
+            felt: (30, 90, 50),
+        }
+    }
+}
+
+impl Palette {
+    pub fn text(&self) -> Color {
+        Color::from(self.text)
+    }
+
+    pub fn felt(&self) -> Color {
+        Color::from(self.felt)
+    }
+}
+
+///The look of the table, read from the manifest in the folder of the theme
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub name: String,
+    pub cards: CardSheet,
+    ///Without it the table is painted with the felt colour
+    pub background: String,
+    ///The image of the menu
+    pub title: String,
+    pub font: String,
+    pub colors: Palette,
+    ///The paths of the manifest are relative to it
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            name: CLASSIC.to_string(),
+            cards: CardSheet::default(),
+            background: "assets/vecteezy_poker-table-green-cloth-on-dark-background-vector-illustration_6325236.jpg".to_string(),
+            title: "assets/title-screen.jpg".to_string(),
+            font: "assets/RetroGaming.ttf".to_string(),
+            colors: Palette::default(),
+            dir: PathBuf::new(),
+        }
+    }
+}
+
+impl Theme {
+    ///Reads the manifest in the folder, the theme is named after the folder if the manifest
+    ///doesn't name it
+    pub fn load<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        let file = BufReader::new(File::open(dir.join(MANIFEST))?);
+        let mut theme: Theme = serde_json::from_reader(file)?;
+
+        if theme.name == CLASSIC {
+            if let Some(name) = dir.file_name() {
+                theme.name = name.to_string_lossy().to_string();
+            }
+        }
+        theme.dir = dir.to_path_buf();
+
+        Ok(theme)
+    }
+
+    ///The classic theme and the ones in the folder, sorted by name
+    pub fn all<P: AsRef<Path>>(themes_dir: P) -> Vec<Theme> {
+        let mut themes = match fs::read_dir(themes_dir) {
+            Ok(entries) => entries
+                .flatten()
+                .filter(|e| e.path().join(MANIFEST).exists())
+                .filter_map(|e| match Theme::load(e.path()) {
+                    Ok(theme) => Some(theme),
+                    Err(err) => {
+                        warn!("Couldn't read the theme in {}: {err}", e.path().display());
+                        None
+                    }
+                })
+                .collect(),
+            Err(_) => vec![],
+        };
+        themes.sort_by(|a, b| a.name.cmp(&b.name));
+        themes.retain(|t| t.name != CLASSIC);
+        themes.insert(0, Theme::default());
+
+        themes
+    }
+
+    ///The theme with the name, the classic one if there isn't any
+    pub fn find<P: AsRef<Path>>(themes_dir: P, name: &str) -> Theme {
+        Theme::all(themes_dir)
+            .into_iter()
+            .find(|t| t.name == name)
+            .unwrap_or_default()
+    }
+
+    ///The path of a file of the manifest
+    pub fn path(&self, file: &str) -> PathBuf {
+        self.dir.join(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_sheet_layout() {
+        let sheet = CardSheet::default();
+
+        let ace = Card::new(Suit::Club, Value::Ace);
+        assert_eq!(sheet.rect(Some(ace), 0), Rect::new(0, 0, 200, 291));
+        let two = Card::new(Suit::Heart, Value::Two);
+        assert_eq!(sheet.rect(Some(two), 0), Rect::new(200, 2 * 291, 200, 291));
+        let king = Card::new(Suit::Spade, Value::King);
+        assert_eq!(sheet.rect(Some(king), 0), Rect::new(12 * 200, 3 * 291, 200, 291));
+
+        //A missing back is the first one
+        assert_eq!(sheet.rect(None, 0), Rect::new(400, 4 * 291, 200, 291));
+        assert_eq!(sheet.rect(None, 5), sheet.rect(None, 0));
+
+        assert_eq!(sheet.size(200), (200, 291));
+        let square = CardSheet {
+            card_height: 200,
+            ..CardSheet::default()
+        };
+        assert_eq!(square.size(52), (52, 52));
+    }
+
+    #[test]
+    fn four_colors() {
+        assert_eq!(four_color(Suit::Club, [10, 10, 10, 255]), [10, 130, 10, 255]);
+        assert_eq!(four_color(Suit::Diamond, [200, 20, 30, 255]), [30, 20, 200, 255]);
+        //The white of the card stays
+        for suit in SUITS {
+            assert_eq!(four_color(suit, [255, 255, 255, 255]), [255, 255, 255, 255]);
+        }
+        assert_eq!(four_color(Suit::Spade, [10, 10, 10, 255]), [10, 10, 10, 255]);
+    }
+
+    #[test]
+    fn manifest_paths_are_relative() -> io::Result<()> {
+        let dir = std::env::temp_dir().join(format!("poker-theme-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(
+            dir.join(MANIFEST),
+            r#"{"cards": {"path": "deck.png", "backs": [[0, 4], [1, 4]]}, "colors": {"felt": [0, 0, 80]}}"#,
+        )?;
+
+        let theme = Theme::load(&dir)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(theme.name, dir.file_name().unwrap().to_string_lossy());
+        assert_eq!(theme.path(&theme.cards.path), dir.join("deck.png"));
+        assert_eq!(theme.cards.card_width, 200);
+        assert_eq!(theme.cards.backs.len(), 2);
+        assert_eq!(theme.colors.felt, (0, 0, 80));
+        assert_eq!(theme.colors.text, (255, 255, 255));
+
+        Ok(())
+    }
+}